@@ -58,6 +58,20 @@ impl Default for EasingList {
             EaseMethod::EaseFunction(bevy_easings::EaseFunction::BounceIn),
             EaseMethod::EaseFunction(bevy_easings::EaseFunction::BounceOut),
             EaseMethod::EaseFunction(bevy_easings::EaseFunction::BounceInOut),
+            "cubic-bezier(0.68, -0.6, 0.32, 1.6)".parse().unwrap(),
+            "steps(5, jump-both)".parse().unwrap(),
+            EaseMethod::closure({
+                let bounces = 3.;
+                move |x| 1. - (x * bounces * std::f32::consts::PI).cos().abs() * (1. - x)
+            }),
+            EaseMethod::Spring {
+                stiffness: 100.,
+                damping: 8.,
+                mass: 1.,
+            },
+            EaseMethod::SmoothDamp {
+                smooth_time: std::time::Duration::from_millis(500),
+            },
         ])
     }
 }
@@ -157,7 +171,7 @@ fn handle_input(
                 -SCREEN_Y,
                 cube.duration,
                 easing.state,
-                ease_functions.0[cube.easing_id],
+                ease_functions.0[cube.easing_id].clone(),
             ));
         }
     }
@@ -183,7 +197,7 @@ fn update_text(
                 &transform.translation,
                 animated_cube.duration,
                 easing_state,
-                ease_functions.0[animated_cube.easing_id],
+                ease_functions.0[animated_cube.easing_id].clone(),
             );
         }
     }
@@ -228,7 +242,13 @@ fn format_info_text(
         EaseMethod::EaseFunction(ease_func) => format!("{:?}", ease_func),
         EaseMethod::Linear => "Linear".to_string(),
         EaseMethod::Discrete => "Discrete".to_string(),
-        EaseMethod::CustomFunction(_) => "Custom".to_string(),
+        EaseMethod::CustomFunction(_) | EaseMethod::CustomClosure(_) => "Custom".to_string(),
+        EaseMethod::CubicBezier { x1, y1, x2, y2 } => {
+            format!("cubic-bezier({x1}, {y1}, {x2}, {y2})")
+        }
+        EaseMethod::Steps { steps, position } => format!("steps({steps}, {position:?})"),
+        EaseMethod::Spring { .. } => "Spring".to_string(),
+        EaseMethod::SmoothDamp { .. } => "SmoothDamp".to_string(),
     };
     let pos = format!("{:.2}x{:.2}", translation.x, translation.y);
 
@@ -25,7 +25,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
 fn switch_menu(
     mut commands: Commands,
-    menu: Query<(Entity, &MenuItem, Option<&ToDespawn>)>,
+    menu: Query<(Entity, &MenuItem)>,
     mut timer: Local<Option<Timer>>,
     time: Res<Time>,
 ) {
@@ -40,10 +40,14 @@ fn switch_menu(
         spawn_menu(&mut commands);
         *timer = Some(Timer::from_seconds(5.0, TimerMode::Once));
     } else {
-        for (entity, item, _) in menu.iter() {
+        // buttons leave one after the other from the last one, then the menu slides out
+        let mut timeline = Timeline::new();
+        for (entity, item) in menu.iter() {
             match item {
                 MenuItem::Root => {
-                    commands.entity(entity).insert((
+                    timeline = timeline.add(
+                        entity,
+                        Duration::from_secs_f32(0.8),
                         Node {
                             width: Val::Percent(100.0),
                             height: Val::Percent(100.0),
@@ -65,13 +69,13 @@ fn switch_menu(
                             EasingType::Once {
                                 duration: Duration::from_secs(1),
                             },
-                        )
-                        .delay(Duration::from_secs_f32(0.8)),
-                        ToDespawn,
-                    ));
+                        ),
+                    );
                 }
                 MenuItem::Button(i) => {
-                    commands.entity(entity).insert(
+                    timeline = timeline.add(
+                        entity,
+                        Duration::from_secs_f32(0.2 * (5 - i) as f32),
                         Node {
                             width: Val::Px(250.0),
                             height: Val::Px(65.0),
@@ -93,28 +97,26 @@ fn switch_menu(
                             EasingType::Once {
                                 duration: Duration::from_secs_f32(1.2),
                             },
-                        )
-                        .delay(Duration::from_secs_f32(0.2 * (5 - i) as f32)),
+                        ),
                     );
                 }
                 _ => (),
             }
         }
+        commands.spawn(timeline);
         *timer = Some(Timer::from_seconds(3.0, TimerMode::Once));
     }
 }
 
-#[derive(Component)]
-struct ToDespawn;
-
 fn despawn_menu(
     mut commands: Commands,
-    menu: Query<&MenuItem, With<ToDespawn>>,
-    mut finished_easing: RemovedComponents<EasingComponent<Node>>,
+    menu: Query<Entity, With<MenuItem>>,
+    mut finished_timeline: MessageReader<EasingCompleted<Timeline>>,
 ) {
-    for finished in finished_easing.read() {
-        if menu.contains(finished) {
-            commands.entity(finished).despawn();
+    for finished in finished_timeline.read() {
+        commands.entity(finished.entity).despawn();
+        for entity in &menu {
+            commands.entity(entity).try_despawn();
         }
     }
 }
@@ -1,6 +1,6 @@
 use bevy::{color::palettes, prelude::*};
 
-use bevy_easings::{Ease, EasingComponent};
+use bevy_easings::{Ease, EasingCompleted};
 use rand::Rng;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -8,7 +8,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .add_plugins(DefaultPlugins)
         .add_plugins(bevy_easings::EasingsPlugin::default())
         .add_systems(Startup, setup)
-        .add_systems(PostUpdate, add_new_easing)
+        .add_observer(add_new_easing)
         .run();
 
     Ok(())
@@ -37,8 +37,8 @@ fn setup(mut commands: Commands, windows: Query<&Window>) {
 }
 
 fn add_new_easing(
+    completed: On<EasingCompleted<Transform>>,
     mut commands: Commands,
-    mut removed: RemovedComponents<EasingComponent<Transform>>,
     transform: Query<&Transform>,
     windows: Query<&Window>,
 ) {
@@ -46,20 +46,18 @@ fn add_new_easing(
         return;
     };
 
-    for entity in removed.read() {
-        let width = window.width() / 2.0;
-        let height = window.height() / 2.0;
-        let x = rand::rng().random_range(-width..width);
-        let y = rand::rng().random_range(-height..height);
+    let width = window.width() / 2.0;
+    let height = window.height() / 2.0;
+    let x = rand::rng().random_range(-width..width);
+    let y = rand::rng().random_range(-height..height);
 
-        commands
-            .entity(entity)
-            .insert(transform.get(entity).unwrap().ease_to(
-                Transform::from_xyz(x, y, 0.0),
-                bevy_easings::EaseFunction::QuadraticInOut,
-                bevy_easings::EasingType::Once {
-                    duration: std::time::Duration::from_millis(2500),
-                },
-            ));
-    }
+    commands
+        .entity(completed.entity)
+        .insert(transform.get(completed.entity).unwrap().ease_to(
+            Transform::from_xyz(x, y, 0.0),
+            bevy_easings::EaseFunction::QuadraticInOut,
+            bevy_easings::EasingType::Once {
+                duration: std::time::Duration::from_millis(2500),
+            },
+        ));
 }
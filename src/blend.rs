@@ -0,0 +1,226 @@
+use bevy_ecs::component::Component;
+
+use crate::{
+    EaseValue, EasingComponent,
+    driver::{EaseSettings, Interpolate},
+};
+
+/// How an eased value is combined with the current value of the component
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum EaseBlend {
+    /// The eased value replaces the current value
+    #[default]
+    Override,
+    /// The change of the eased value since the last update is added to the current value
+    Additive,
+    /// The value of the component when the easing started is interpolated toward the eased value with this weight,
+    /// between 0 and 1
+    Weighted(f32),
+}
+
+/// Values that can be added together, used by [`EaseBlend::Additive`] easings.
+///
+/// For rotations, adding is composing the transformations. Scales are added as differences from a scale of one, so
+/// that a change can be taken from a zero scale.
+pub trait Additive {
+    /// Add `other` to `self`
+    fn add(&self, other: &Self) -> Self;
+    /// Remove `other` from `self`
+    fn sub(&self, other: &Self) -> Self;
+}
+
+#[derive(Clone)]
+pub(crate) enum Blend<T> {
+    Override,
+    Additive {
+        add: fn(&T, &T, &T) -> T,
+        previous: Option<EaseValue<T>>,
+    },
+    Weighted {
+        weight: f32,
+        base: Option<EaseValue<T>>,
+    },
+}
+
+impl<T> Blend<T> {
+    pub(crate) fn additive() -> Self
+    where
+        T: Additive,
+    {
+        Blend::Additive {
+            add: |current, previous, eased| current.add(&eased.sub(previous)),
+            previous: None,
+        }
+    }
+}
+
+impl<T> EasingComponent<T> {
+    /// Add the change of the eased value to the current value of the component instead of replacing it.
+    ///
+    /// This lets this easing compose with other systems or easings changing the same component.
+    pub fn additive(mut self) -> Self
+    where
+        T: Additive,
+    {
+        self.blend = Blend::additive();
+        self
+    }
+
+    /// Interpolate the value of the component when the easing started toward the eased value with `weight`,
+    /// between 0 and 1, instead of replacing it.
+    ///
+    /// In [`EasingTracks`], the value written by the easings applied before this one is used instead.
+    pub fn weighted(mut self, weight: f32) -> Self {
+        self.blend = Blend::Weighted { weight, base: None };
+        self
+    }
+
+    /// How the eased value is combined with the current value of the component
+    pub fn blend(&self) -> EaseBlend {
+        match self.blend {
+            Blend::Override => EaseBlend::Override,
+            Blend::Additive { .. } => EaseBlend::Additive,
+            Blend::Weighted { weight, .. } => EaseBlend::Weighted(weight),
+        }
+    }
+}
+
+impl<T: Default> EasingComponent<T> {
+    /// Combine the `eased` value with the `current` value of the component, following the blend of this easing.
+    pub(crate) fn blend_into<I: Interpolate<T>>(
+        &mut self,
+        current: T,
+        eased: T,
+        settings: &EaseSettings,
+    ) -> T {
+        match &mut self.blend {
+            Blend::Override => eased,
+            Blend::Weighted { weight, base } => {
+                // the base is kept from the first update, so that the easing doesn't blend with its own output
+                let base = base.get_or_insert(EaseValue(current));
+                I::interpolate(base, &EaseValue(eased), *weight, settings)
+            }
+            Blend::Additive { add, previous } => {
+                let value = match (previous.as_ref(), self.start.as_ref()) {
+                    (Some(previous), _) | (None, Some(previous)) => {
+                        add(&current, &previous.0, &eased)
+                    }
+                    (None, None) => add(&current, &T::default(), &eased),
+                };
+                *previous = Some(EaseValue(eased));
+                value
+            }
+        }
+    }
+
+    /// Combine the `eased` value with `below`, the value written by the easings applied before this one in the same
+    /// update.
+    pub(crate) fn blend_over<I: Interpolate<T>>(
+        &mut self,
+        below: T,
+        eased: T,
+        settings: &EaseSettings,
+    ) -> T {
+        match self.blend {
+            Blend::Weighted { weight, .. } => {
+                I::interpolate(&EaseValue(below), &EaseValue(eased), weight, settings)
+            }
+            _ => self.blend_into::<I>(below, eased, settings),
+        }
+    }
+}
+
+/// Component to run several easings on the same component at the same time.
+///
+/// Easings are applied in order, each one combined with the result of the previous ones following its
+/// [`EaseBlend`]. The component is removed once all easings finished.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_easings::{Ease, EaseFunction, EasingTracks, EasingType};
+/// use std::time::Duration;
+///
+/// fn system(mut commands: Commands) {
+///     let transform = Transform::default();
+///     commands.spawn((
+///         transform,
+///         EasingTracks::new()
+///             // idle bobbing
+///             .with(
+///                 transform
+///                     .ease_to(
+///                         transform.with_translation(Vec3::Y * 10.),
+///                         EaseFunction::SineInOut,
+///                         EasingType::PingPong { duration: Duration::from_secs(1), pause: None },
+///                     )
+///                     .additive(),
+///             )
+///             // hit shake
+///             .with(
+///                 transform
+///                     .ease_to(
+///                         transform.with_translation(Vec3::X * 5.),
+///                         EaseFunction::ElasticOut,
+///                         EasingType::Once { duration: Duration::from_millis(300) },
+///                     )
+///                     .additive(),
+///             ),
+///     ));
+/// }
+/// ```
+#[derive(Component)]
+#[component(on_add = crate::plugin::check_registered::<T>)]
+pub struct EasingTracks<T>(pub(crate) Vec<EasingComponent<T>>);
+
+impl<T> EasingTracks<T> {
+    /// Create an empty set of easings
+    pub fn new() -> Self {
+        EasingTracks(Vec::new())
+    }
+
+    /// Add an easing, applied after the previous ones
+    pub fn with(mut self, easing: EasingComponent<T>) -> Self {
+        self.0.push(easing);
+        self
+    }
+
+    /// Add an easing, applied after the previous ones
+    pub fn push(&mut self, easing: EasingComponent<T>) {
+        self.0.push(easing);
+    }
+
+    /// The easings currently running
+    pub fn tracks(&self) -> &[EasingComponent<T>] {
+        &self.0
+    }
+
+    /// The easings currently running
+    pub fn tracks_mut(&mut self) -> &mut [EasingComponent<T>] {
+        &mut self.0
+    }
+}
+
+impl<T> Default for EasingTracks<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<EasingComponent<T>> for EasingTracks<T> {
+    fn from(easing: EasingComponent<T>) -> Self {
+        EasingTracks(vec![easing])
+    }
+}
+
+impl<T: Clone> Clone for EasingTracks<T> {
+    fn clone(&self) -> Self {
+        EasingTracks(self.0.clone())
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for EasingTracks<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("EasingTracks").field(&self.0).finish()
+    }
+}
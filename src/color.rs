@@ -0,0 +1,306 @@
+use std::sync::Arc;
+
+use bevy_color::{
+    Color, ColorToComponents, Hsla, Hsva, Hwba, Laba, Lcha, LinearRgba, Oklaba, Oklcha, Srgba, Xyza,
+};
+
+use bevy_ecs::resource::Resource;
+
+use crate::{EasingComponent, driver::EaseSettings};
+
+/// How the hue of a color in a cylindrical color space ([`Hsla`], [`Hsva`], [`Hwba`], [`Lcha`] and [`Oklcha`]) goes
+/// around the color wheel, following [CSS Color 4](https://www.w3.org/TR/css-color-4/#hue-interpolation)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HueInterpolation {
+    /// Take the shorter arc between the two hues
+    #[default]
+    Shorter,
+    /// Take the longer arc between the two hues
+    Longer,
+    /// Go around the color wheel with an increasing hue
+    Increasing,
+    /// Go around the color wheel with a decreasing hue
+    Decreasing,
+}
+
+impl HueInterpolation {
+    /// Hue in degrees between `start` and `end` at `factor`
+    pub fn interpolate(&self, start: f32, end: f32, factor: f32) -> f32 {
+        let (mut start, mut end) = (start.rem_euclid(360.), end.rem_euclid(360.));
+        let arc = end - start;
+        match self {
+            HueInterpolation::Shorter if arc > 180. => start += 360.,
+            HueInterpolation::Shorter if arc < -180. => end += 360.,
+            HueInterpolation::Longer if 0. < arc && arc < 180. => start += 360.,
+            HueInterpolation::Longer if -180. < arc && arc <= 0. => end += 360.,
+            HueInterpolation::Increasing if end < start => end += 360.,
+            HueInterpolation::Decreasing if start < end => start += 360.,
+            _ => (),
+        }
+        (start + (end - start) * factor).rem_euclid(360.)
+    }
+}
+
+/// Color space in which colors are interpolated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorInterpolation {
+    /// Interpolate in the color space of the start color
+    #[default]
+    Start,
+    /// Interpolate in [`Srgba`]
+    Srgb,
+    /// Interpolate in [`LinearRgba`]
+    LinearRgb,
+    /// Interpolate in [`Hsla`]
+    Hsl,
+    /// Interpolate in [`Hsva`]
+    Hsv,
+    /// Interpolate in [`Hwba`]
+    Hwb,
+    /// Interpolate in [`Laba`]
+    Lab,
+    /// Interpolate in [`Lcha`]
+    Lch,
+    /// Interpolate in [`Oklaba`]
+    Oklab,
+    /// Interpolate in [`Oklcha`]
+    Oklch,
+    /// Interpolate in [`Xyza`]
+    Xyz,
+}
+
+impl ColorInterpolation {
+    /// Color space of `color`
+    fn of(color: Color) -> Self {
+        match color {
+            Color::Srgba(_) => ColorInterpolation::Srgb,
+            Color::LinearRgba(_) => ColorInterpolation::LinearRgb,
+            Color::Hsla(_) => ColorInterpolation::Hsl,
+            Color::Hsva(_) => ColorInterpolation::Hsv,
+            Color::Hwba(_) => ColorInterpolation::Hwb,
+            Color::Laba(_) => ColorInterpolation::Lab,
+            Color::Lcha(_) => ColorInterpolation::Lch,
+            Color::Oklaba(_) => ColorInterpolation::Oklab,
+            Color::Oklcha(_) => ColorInterpolation::Oklch,
+            Color::Xyza(_) => ColorInterpolation::Xyz,
+        }
+    }
+
+    /// Convert `color` to this color space, keeping it as is for [`ColorInterpolation::Start`]
+    fn convert(&self, color: Color) -> Color {
+        match self {
+            ColorInterpolation::Start => color,
+            ColorInterpolation::Srgb => Color::Srgba(Srgba::from(color)),
+            ColorInterpolation::LinearRgb => Color::LinearRgba(LinearRgba::from(color)),
+            ColorInterpolation::Hsl => Color::Hsla(Hsla::from(color)),
+            ColorInterpolation::Hsv => Color::Hsva(Hsva::from(color)),
+            ColorInterpolation::Hwb => Color::Hwba(Hwba::from(color)),
+            ColorInterpolation::Lab => Color::Laba(Laba::from(color)),
+            ColorInterpolation::Lch => Color::Lcha(Lcha::from(color)),
+            ColorInterpolation::Oklab => Color::Oklaba(Oklaba::from(color)),
+            ColorInterpolation::Oklch => Color::Oklcha(Oklcha::from(color)),
+            ColorInterpolation::Xyz => Color::Xyza(Xyza::from(color)),
+        }
+    }
+}
+
+/// How colors are interpolated.
+///
+/// It can be set for all easings of an app by inserting it as a resource, or for a single easing with
+/// [`EasingComponent::with_color_mix`].
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Default)]
+pub struct ColorMix {
+    /// How the hue goes around the color wheel in cylindrical color spaces
+    pub hue: HueInterpolation,
+    /// Color space in which colors are interpolated. The result is converted back to the color space of the start
+    /// color
+    pub space: ColorInterpolation,
+    /// Interpolate colors with premultiplied alpha, so that a transparent color doesn't tint the other one
+    pub premultiplied: bool,
+}
+
+impl ColorMix {
+    /// Interpolation of colors with the default settings
+    pub const fn new() -> Self {
+        Self {
+            hue: HueInterpolation::Shorter,
+            space: ColorInterpolation::Start,
+            premultiplied: false,
+        }
+    }
+
+    /// Set how the hue goes around the color wheel
+    pub fn hue(mut self, hue: HueInterpolation) -> Self {
+        self.hue = hue;
+        self
+    }
+
+    /// Set the color space in which colors are interpolated
+    pub fn space(mut self, space: ColorInterpolation) -> Self {
+        self.space = space;
+        self
+    }
+
+    /// Set whether colors are interpolated with premultiplied alpha
+    pub fn premultiplied(mut self, premultiplied: bool) -> Self {
+        self.premultiplied = premultiplied;
+        self
+    }
+
+    /// Interpolate `start` and `end` in the color space of `start`
+    fn mix<C: ColorToComponents + From<Color>>(
+        &self,
+        start: C,
+        end: Color,
+        factor: f32,
+        hue: Option<usize>,
+    ) -> C {
+        let (mut start, mut end) = (start.to_vec4(), C::from(end).to_vec4());
+        // the hue is an angle, it is not premultiplied
+        let channels = (0..3).filter(|channel| Some(*channel) != hue);
+        if self.premultiplied {
+            for channel in channels.clone() {
+                start[channel] *= start.w;
+                end[channel] *= end.w;
+            }
+        }
+        let mut mixed = start.lerp(end, factor);
+        if self.premultiplied && mixed.w > 0. {
+            for channel in channels {
+                mixed[channel] /= mixed.w;
+            }
+        }
+        if let Some(hue) = hue {
+            mixed[hue] = self.hue.interpolate(start[hue], end[hue], factor);
+        }
+        C::from_vec4(mixed)
+    }
+}
+
+/// Types holding a color, that can be interpolated with a [`ColorMix`]
+pub trait LerpColor: Sized {
+    /// Interpolate between `self` and `other` at `factor`
+    fn lerp_color(&self, other: &Self, factor: f32, mix: &ColorMix) -> Self;
+}
+
+impl LerpColor for Color {
+    fn lerp_color(&self, other: &Self, factor: f32, mix: &ColorMix) -> Self {
+        let other = *other;
+        let mixed = match mix.space.convert(*self) {
+            Color::Srgba(color) => Color::Srgba(mix.mix(color, other, factor, None)),
+            Color::LinearRgba(color) => Color::LinearRgba(mix.mix(color, other, factor, None)),
+            Color::Hsla(color) => Color::Hsla(mix.mix(color, other, factor, Some(0))),
+            Color::Hsva(color) => Color::Hsva(mix.mix(color, other, factor, Some(0))),
+            Color::Hwba(color) => Color::Hwba(mix.mix(color, other, factor, Some(0))),
+            Color::Laba(color) => Color::Laba(mix.mix(color, other, factor, None)),
+            Color::Lcha(color) => Color::Lcha(mix.mix(color, other, factor, Some(2))),
+            Color::Oklaba(color) => Color::Oklaba(mix.mix(color, other, factor, None)),
+            Color::Oklcha(color) => Color::Oklcha(mix.mix(color, other, factor, Some(2))),
+            Color::Xyza(color) => Color::Xyza(mix.mix(color, other, factor, None)),
+        };
+        ColorInterpolation::of(*self).convert(mixed)
+    }
+}
+
+#[cfg(feature = "sprite")]
+impl LerpColor for bevy_sprite::Sprite {
+    fn lerp_color(&self, other: &Self, factor: f32, mix: &ColorMix) -> Self {
+        use interpolation::Lerp;
+
+        bevy_sprite::Sprite {
+            color: self.color.lerp_color(&other.color, factor, mix),
+            ..crate::EaseValue(self.clone())
+                .lerp(&crate::EaseValue(other.clone()), &factor)
+                .0
+        }
+    }
+}
+
+#[cfg(feature = "ui")]
+impl LerpColor for bevy_ui::BackgroundColor {
+    fn lerp_color(&self, other: &Self, factor: f32, mix: &ColorMix) -> Self {
+        bevy_ui::BackgroundColor(self.0.lerp_color(&other.0, factor, mix))
+    }
+}
+
+#[cfg(feature = "ui")]
+impl LerpColor for bevy_ui::BorderColor {
+    fn lerp_color(&self, other: &Self, factor: f32, mix: &ColorMix) -> Self {
+        bevy_ui::BorderColor {
+            top: self.top.lerp_color(&other.top, factor, mix),
+            right: self.right.lerp_color(&other.right, factor, mix),
+            bottom: self.bottom.lerp_color(&other.bottom, factor, mix),
+            left: self.left.lerp_color(&other.left, factor, mix),
+        }
+    }
+}
+
+#[cfg(all(feature = "ui", feature = "render"))]
+impl LerpColor for bevy_ui::Outline {
+    fn lerp_color(&self, other: &Self, factor: f32, mix: &ColorMix) -> Self {
+        use interpolation::Lerp;
+
+        bevy_ui::Outline {
+            color: self.color.lerp_color(&other.color, factor, mix),
+            ..crate::EaseValue(*self)
+                .lerp(&crate::EaseValue(*other), &factor)
+                .0
+        }
+    }
+}
+
+#[cfg(all(feature = "ui", feature = "render"))]
+impl LerpColor for bevy_ui::widget::ImageNode {
+    fn lerp_color(&self, other: &Self, factor: f32, mix: &ColorMix) -> Self {
+        use interpolation::Lerp;
+
+        bevy_ui::widget::ImageNode {
+            color: self.color.lerp_color(&other.color, factor, mix),
+            ..crate::EaseValue(self.clone())
+                .lerp(&crate::EaseValue(other.clone()), &factor)
+                .0
+        }
+    }
+}
+
+#[cfg(feature = "ui")]
+impl LerpColor for bevy_text::TextColor {
+    fn lerp_color(&self, other: &Self, factor: f32, mix: &ColorMix) -> Self {
+        bevy_text::TextColor(self.0.lerp_color(&other.0, factor, mix))
+    }
+}
+
+impl<T: LerpColor + 'static> EasingComponent<T> {
+    /// Interpolate colors with `mix` instead of the [`ColorMix`] resource
+    pub fn with_color_mix(self, mix: ColorMix) -> Self {
+        self.with_current_color_mix(move |_| mix)
+    }
+
+    /// Interpolate colors in `space`, keeping the other settings of the [`ColorMix`] resource
+    pub fn with_color_space(self, space: ColorInterpolation) -> Self {
+        self.with_current_color_mix(move |mix| mix.space(space))
+    }
+
+    /// Interpolate colors with premultiplied alpha, keeping the other settings of the [`ColorMix`] resource
+    pub fn with_premultiplied_alpha(self) -> Self {
+        self.with_current_color_mix(|mix| mix.premultiplied(true))
+    }
+
+    /// Interpolate hues with `hue`, keeping the other settings of the [`ColorMix`] resource
+    pub fn with_hue(self, hue: HueInterpolation) -> Self {
+        self.with_current_color_mix(move |mix| mix.hue(hue))
+    }
+
+    /// Interpolate colors with the settings returned by `change` from the [`ColorMix`] resource
+    fn with_current_color_mix(
+        mut self,
+        change: impl Fn(ColorMix) -> ColorMix + Send + Sync + 'static,
+    ) -> Self {
+        self.interpolation = Some(Arc::new(
+            move |start: &T, end: &T, factor, settings: &EaseSettings| {
+                start.lerp_color(end, factor, &change(settings.color_mix))
+            },
+        ));
+        self
+    }
+}
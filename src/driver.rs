@@ -0,0 +1,657 @@
+use std::time::Duration;
+
+use bevy_ecs::{
+    change_detection::{DetectChangesMut, Mut},
+    component::{Component, Mutable},
+    entity::Entity,
+    query::With,
+    system::{Commands, Query},
+};
+use interpolation::Lerp;
+
+use crate::{
+    EaseValue, EasingChainComponent, EasingComponent, EasingDirection, EasingKeyframes, EasingLens,
+    EasingState, EasingTracks, EasingType, Lens, MyEaser, Repeat, RetargetPolicy,
+    events::{
+        EasingChainStepCompleted, EasingCompleted, EasingLoopCompleted, EasingPingPongReversed,
+        send_easing_event,
+    },
+};
+
+/// Settings of the app used to interpolate values, read from its resources by the easing systems
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct EaseSettings {
+    /// How colors are interpolated, from the [`ColorMix`](crate::ColorMix) resource
+    #[cfg(feature = "color")]
+    pub(crate) color_mix: crate::ColorMix,
+}
+
+/// Bridge between the ways a type can be eased: either `EaseValue<C>` implements [`Lerp`], `C` itself does, or `C`
+/// holds a color interpolated with the settings of the app.
+pub(crate) trait Interpolate<C> {
+    fn interpolate(
+        start: &EaseValue<C>,
+        end: &EaseValue<C>,
+        factor: f32,
+        settings: &EaseSettings,
+    ) -> C;
+}
+
+/// Interpolate types for which `EaseValue<C>` implements [`Lerp`]
+pub(crate) struct EaseValueLerp;
+
+impl<C> Interpolate<C> for EaseValueLerp
+where
+    EaseValue<C>: Lerp<Scalar = f32>,
+{
+    fn interpolate(
+        start: &EaseValue<C>,
+        end: &EaseValue<C>,
+        factor: f32,
+        _settings: &EaseSettings,
+    ) -> C {
+        interpolation::lerp(start, end, &factor).0
+    }
+}
+
+/// Interpolate types that implement [`Lerp`]
+pub(crate) struct ComponentLerp;
+
+impl<C> Interpolate<C> for ComponentLerp
+where
+    C: Lerp<Scalar = f32>,
+{
+    fn interpolate(
+        start: &EaseValue<C>,
+        end: &EaseValue<C>,
+        factor: f32,
+        _settings: &EaseSettings,
+    ) -> C {
+        interpolation::lerp(&start.0, &end.0, &factor)
+    }
+}
+
+/// Interpolate types holding a color with the [`ColorMix`](crate::ColorMix) of the app
+#[cfg(feature = "color")]
+pub(crate) struct ColorLerp;
+
+#[cfg(feature = "color")]
+impl<C: crate::LerpColor> Interpolate<C> for ColorLerp {
+    fn interpolate(
+        start: &EaseValue<C>,
+        end: &EaseValue<C>,
+        factor: f32,
+        settings: &EaseSettings,
+    ) -> C {
+        start.0.lerp_color(&end.0, factor, &settings.color_mix)
+    }
+}
+
+/// A movement an easing blends from after a retarget, continuing as if it had not been retargeted
+#[derive(Clone)]
+pub(crate) struct Fading<T> {
+    start: EaseValue<T>,
+    end: EaseValue<T>,
+    /// Progress on the easing curve when it was retargeted
+    progress: f32,
+    /// Progress per second on the easing curve
+    rate: f32,
+}
+
+impl<T: Default> Fading<T> {
+    /// Value of the movement `elapsed` after `easing` was retargeted
+    fn value<I: Interpolate<T>>(
+        &self,
+        easing: &EasingComponent<T>,
+        elapsed: Duration,
+        settings: &EaseSettings,
+    ) -> T {
+        let progress = (self.progress + self.rate * elapsed.as_secs_f32()).clamp(0., 1.);
+        easing.interpolate::<I>(
+            &self.start,
+            &self.end,
+            easing.ease_function.factor(progress),
+            settings,
+        )
+    }
+}
+
+/// What happened to an easing when it reached its end
+#[derive(Clone, Copy)]
+pub(crate) enum EasingStep {
+    /// An [`EasingType::Once`] easing finished, with the time elapsed after its end
+    Completed(Duration),
+    /// An [`EasingType::Loop`] easing restarted
+    LoopCompleted,
+    /// An [`EasingType::PingPong`] easing changed direction
+    Reversed,
+}
+
+impl<T: Default> EasingComponent<T> {
+    /// Advance the easing by `delta`, returning the new eased value if it changed.
+    pub(crate) fn advance<I: Interpolate<T>>(
+        &mut self,
+        delta: Duration,
+        settings: &EaseSettings,
+    ) -> (Option<T>, Option<EasingStep>) {
+        if self.state == EasingState::Paused {
+            // a paused easing is only updated after moving it in time
+            if !std::mem::take(&mut self.seeked) {
+                return (None, None);
+            }
+            return (Some(self.value::<I>(settings)), None);
+        }
+        self.seeked = false;
+        self.apply_retarget::<I>(settings);
+        let delta = delta.mul_f32(self.speed.abs());
+        // the first delay is not part of the time repeating
+        if !self.paused || self.iterations > 0 {
+            self.played += delta;
+        }
+        if self.paused {
+            self.timer.tick(delta);
+            if self.timer.just_finished() {
+                self.timer.set_duration(self.duration());
+                self.timer.reset();
+                self.paused = false;
+                if self.speed < 0. {
+                    self.timer.set_elapsed(self.timer.duration());
+                }
+            }
+            return (None, None);
+        }
+        if self.speed < 0. {
+            return self.rewind::<I>(delta, settings);
+        }
+        let remaining = self.timer.remaining();
+        self.timer.tick(delta);
+
+        let mut eased = None;
+        if self.timer.duration().as_secs_f32() != 0. {
+            eased = Some(self.value::<I>(settings));
+        }
+
+        if !self.timer.is_finished() {
+            return (eased, None);
+        }
+        self.end_iteration();
+        let step = match self.easing_type {
+            EasingType::Once { .. } => Some(EasingStep::Completed(delta.saturating_sub(remaining))),
+            _ if self.repeated() => Some(EasingStep::Completed(delta.saturating_sub(remaining))),
+            EasingType::Loop { pause, .. } => {
+                if let Some(pause) = pause {
+                    self.timer.set_duration(pause);
+                    self.paused = true;
+                }
+                self.timer.reset();
+                Some(EasingStep::LoopCompleted)
+            }
+            EasingType::PingPong { pause, .. } => {
+                if let Some(pause) = pause {
+                    self.timer.set_duration(pause);
+                    self.paused = true;
+                }
+                self.timer.reset();
+                self.direction.reverse();
+                Some(EasingStep::Reversed)
+            }
+        };
+        (eased, step)
+    }
+
+    /// Move the easing back by `delta`, toward its start.
+    fn rewind<I: Interpolate<T>>(
+        &mut self,
+        delta: Duration,
+        settings: &EaseSettings,
+    ) -> (Option<T>, Option<EasingStep>) {
+        let elapsed = self.timer.elapsed();
+        if delta < elapsed {
+            self.timer.set_elapsed(elapsed - delta);
+            return (Some(self.value::<I>(settings)), None);
+        }
+        self.timer.set_elapsed(Duration::ZERO);
+        let eased = Some(self.value::<I>(settings));
+        self.end_iteration();
+        let pause = match self.easing_type {
+            EasingType::Once { .. } => {
+                return (eased, Some(EasingStep::Completed(delta - elapsed)));
+            }
+            _ if self.repeated() => return (eased, Some(EasingStep::Completed(delta - elapsed))),
+            EasingType::Loop { pause, .. } | EasingType::PingPong { pause, .. } => pause,
+        };
+        if let Some(pause) = pause {
+            self.timer.set_duration(pause);
+            self.timer.reset();
+            self.paused = true;
+        } else {
+            self.timer.set_elapsed(self.timer.duration());
+        }
+        match self.easing_type {
+            EasingType::PingPong { .. } => {
+                self.direction.reverse();
+                (eased, Some(EasingStep::Reversed))
+            }
+            _ => (eased, Some(EasingStep::LoopCompleted)),
+        }
+    }
+
+    /// Count an iteration that ended, which also ends the effects of a retarget
+    fn end_iteration(&mut self) {
+        self.iterations += 1;
+        self.factor_offset = 0.;
+        self.fading = None;
+    }
+
+    /// Whether a repeating easing played all its iterations
+    fn repeated(&self) -> bool {
+        match self.repeat {
+            Repeat::Forever => false,
+            Repeat::Times(times) => self.iterations >= times,
+            Repeat::For(duration) => self.played >= duration,
+        }
+    }
+
+    /// Interpolate between `start` and `end`, with the interpolation of this easing if it has one
+    fn interpolate<I: Interpolate<T>>(
+        &self,
+        start: &EaseValue<T>,
+        end: &EaseValue<T>,
+        factor: f32,
+        settings: &EaseSettings,
+    ) -> T {
+        match self.interpolation {
+            Some(ref interpolation) => interpolation(&start.0, &end.0, factor, settings),
+            None => I::interpolate(start, end, factor, settings),
+        }
+    }
+
+    /// Current eased value
+    fn value<I: Interpolate<T>>(&self, settings: &EaseSettings) -> T {
+        let (factor, _) = self.factor();
+        let eased = match self.start {
+            Some(ref start) => self.interpolate::<I>(start, &self.end, factor, settings),
+            None => self.interpolate::<I>(&EaseValue(T::default()), &self.end, factor, settings),
+        };
+        match self.fading {
+            Some(ref fading) => {
+                let previous = fading.value::<I>(self, self.timer.elapsed(), settings);
+                // smoothstep, so that the blend starts with the velocity of the previous movement
+                let progress = self.timer.fraction();
+                let weight = progress * progress * (3. - 2. * progress);
+                I::interpolate(&EaseValue(previous), &EaseValue(eased), weight, settings)
+            }
+            None => eased,
+        }
+    }
+
+    /// Progress on the easing curve, which goes back when the easing is backward
+    fn curve_progress(&self) -> f32 {
+        if self.direction == EasingDirection::Forward {
+            self.timer.fraction()
+        } else {
+            self.timer.fraction_remaining()
+        }
+    }
+
+    /// Current easing factor, and its velocity in factor per second for springs
+    fn factor(&self) -> (f32, f32) {
+        let progress = self.curve_progress();
+        match self.ease_function.spring() {
+            Some(_) if progress >= 1. => (1., 0.),
+            Some(spring) => spring.state(
+                self.velocity,
+                progress * self.timer.duration().as_secs_f32(),
+            ),
+            None if self.factor_offset != 0. => (
+                (progress.compute(&self.ease_function) - self.factor_offset)
+                    / (1. - self.factor_offset),
+                0.,
+            ),
+            None => (progress.compute(&self.ease_function), 0.),
+        }
+    }
+
+    /// Restart the easing from its current value toward the new target set with [`EasingComponent::retarget`],
+    /// or back toward where it came from after [`EasingComponent::toggle`], keeping the velocity of springs.
+    fn apply_retarget<I: Interpolate<T>>(&mut self, settings: &EaseSettings) {
+        let toggled = std::mem::take(&mut self.toggled);
+        if self.retarget.is_none() && !toggled {
+            return;
+        }
+        if self.paused {
+            if let Some((end, _)) = self.retarget.take() {
+                self.end = end;
+            }
+            return;
+        }
+        let (factor, velocity) = self.factor();
+        let current = self.value::<I>(settings);
+        let progress = self.curve_progress();
+        let (elapsed, remaining) = (self.timer.elapsed(), self.timer.remaining());
+        let previous_duration = self.timer.duration();
+        // the new end, where it was as a factor of the distance from start to end, and how to reach it
+        let (end, target, policy) = match self.retarget.take() {
+            Some((end, policy)) => (Some(end), 1., Some(policy)),
+            None if self.direction == EasingDirection::Forward => (
+                Some(self.start.take().unwrap_or_else(|| EaseValue(T::default()))),
+                0.,
+                None,
+            ),
+            None => (None, 1., None),
+        };
+        // velocity was relative to the distance from start to end, it is now relative to the remaining distance
+        self.velocity =
+            if self.direction == EasingDirection::Forward && (target - factor).abs() > 1e-3 {
+                velocity / (target - factor)
+            } else {
+                0.
+            };
+        let previous_start = self.start.replace(EaseValue(current));
+        let previous_end = end.map(|end| std::mem::replace(&mut self.end, end));
+        let previous_direction = std::mem::replace(&mut self.direction, EasingDirection::Forward);
+        self.factor_offset = 0.;
+        self.fading = None;
+        self.timer.set_duration(self.duration());
+        self.timer.reset();
+        if self.ease_function.spring().is_some() {
+            return;
+        }
+        match policy {
+            // toggled back toward where the movement came from
+            None => self.timer.set_duration(elapsed),
+            Some(RetargetPolicy::Restart) => (),
+            Some(RetargetPolicy::KeepSpeed) if (1. - factor).abs() > 1e-3 => {
+                self.timer
+                    .set_elapsed(self.timer.duration().mul_f32(progress));
+                self.factor_offset = factor;
+            }
+            Some(RetargetPolicy::KeepRemainingTime | RetargetPolicy::KeepSpeed) => {
+                self.timer.set_duration(remaining);
+            }
+            Some(RetargetPolicy::BlendFromVelocity) => {
+                self.timer.set_duration(remaining);
+                if let Some(end) = previous_end {
+                    self.fading = Some(Fading {
+                        start: previous_start.unwrap_or_else(|| EaseValue(T::default())),
+                        end,
+                        progress,
+                        rate: previous_direction as i8 as f32 / previous_duration.as_secs_f32(),
+                    });
+                }
+            }
+        }
+    }
+
+    /// Value of the easing played once forward, `time` after it started.
+    pub(crate) fn sample<I: Interpolate<T>>(
+        &mut self,
+        time: Duration,
+        settings: &EaseSettings,
+    ) -> T {
+        self.direction = EasingDirection::Forward;
+        self.timer.set_duration(self.duration());
+        self.timer.set_elapsed(time.min(self.timer.duration()));
+        self.value::<I>(settings)
+    }
+
+    /// Prepare an easing popped from a chain to start from `current`, returning its starting value.
+    pub(crate) fn start_from<I: Interpolate<T>>(
+        &mut self,
+        current: T,
+        settings: &EaseSettings,
+    ) -> T {
+        if self.start.is_none() {
+            self.start = Some(EaseValue(current));
+        }
+        if let Some(ref start) = self.start {
+            self.interpolate::<I>(start, &self.end, 0., settings)
+        } else {
+            self.interpolate::<I>(&EaseValue(T::default()), &self.end, 0., settings)
+        }
+    }
+}
+
+/// Drive all easings and chains of easings of `C`, interpolating with `I`.
+///
+/// This is shared by all the easing systems so that every component has the same semantics.
+pub(crate) fn drive_easings<C, I>(
+    commands: &mut Commands,
+    delta: Duration,
+    entity_query: &Query<Entity, With<C>>,
+    object_query: &mut Query<&mut C>,
+    easing_query: &mut Query<&mut EasingComponent<C>>,
+    chain_query: &mut Query<&mut EasingChainComponent<C>>,
+    settings: &EaseSettings,
+) where
+    C: Component<Mutability = Mutable> + Default,
+    I: Interpolate<C>,
+{
+    let mut steps = Vec::new();
+    for entity in entity_query.iter() {
+        let mut chain = chain_query.get_mut(entity).ok();
+        let Ok(mut object) = object_query.get_mut(entity) else {
+            continue;
+        };
+        if let Ok(mut easing) = easing_query.get_mut(entity) {
+            if run_easing::<C, I>(
+                delta,
+                &mut easing,
+                &mut object,
+                chain.as_deref_mut(),
+                &mut steps,
+                settings,
+            ) {
+                commands
+                    .entity(entity)
+                    .remove::<(EasingComponent<C>, EasingChainComponent<C>)>();
+            }
+        } else if let Some(chain) = chain.as_deref_mut() {
+            let Some(mut easing) = chain.0.pop() else {
+                commands.entity(entity).remove::<EasingChainComponent<C>>();
+                continue;
+            };
+            *object = easing.start_from::<I>(std::mem::take(&mut object), settings);
+            if run_easing::<C, I>(
+                delta,
+                &mut easing,
+                &mut object,
+                Some(chain),
+                &mut steps,
+                settings,
+            ) {
+                commands.entity(entity).remove::<EasingChainComponent<C>>();
+            } else {
+                commands.entity(entity).insert(easing);
+            }
+        }
+        // events are sent after the components are removed or inserted, so that observers can start new easings
+        for (step, direction, chained) in steps.drain(..) {
+            send_step_event::<C>(commands, entity, step, direction, chained);
+        }
+    }
+}
+
+/// Advance `easing` by `delta`. When it finishes, the next easing of `chain` replaces it and is advanced by the
+/// time left in the same update, so that no time is lost between easings of a chain.
+///
+/// The steps reached are pushed to `steps` with their direction and the number of easings left in the chain, to be
+/// sent as events by the caller. Returns `true` once the easing and all of its chain completed.
+fn run_easing<C, I>(
+    mut delta: Duration,
+    easing: &mut EasingComponent<C>,
+    object: &mut Mut<C>,
+    mut chain: Option<&mut EasingChainComponent<C>>,
+    steps: &mut Vec<(EasingStep, EasingDirection, usize)>,
+    settings: &EaseSettings,
+) -> bool
+where
+    C: Component<Mutability = Mutable> + Default,
+    I: Interpolate<C>,
+{
+    loop {
+        let (eased, step) = easing.advance::<I>(delta, settings);
+        if let Some(eased) = eased {
+            **object = easing.blend_into::<I>(std::mem::take(&mut **object), eased, settings);
+        }
+        let Some(step) = step else {
+            return false;
+        };
+        let EasingStep::Completed(overshoot) = step else {
+            steps.push((step, easing.direction, 0));
+            return false;
+        };
+        let chained = chain.as_ref().map_or(0, |chain| chain.0.len());
+        steps.push((step, easing.direction, chained));
+        let Some(mut next) = chain.as_mut().and_then(|chain| chain.0.pop()) else {
+            return true;
+        };
+        **object = next.start_from::<I>(std::mem::take(&mut **object), settings);
+        *easing = next;
+        delta = overshoot;
+    }
+}
+
+/// Drive all easings of the field of a component accessed through the lens `L`, interpolating with `I`.
+pub(crate) fn drive_lens_easings<L, I>(
+    commands: &mut Commands,
+    delta: Duration,
+    query: &mut Query<(Entity, &mut EasingLens<L>, &mut L::Component)>,
+    settings: &EaseSettings,
+) where
+    L: Lens,
+    L::Field: Clone + Default,
+    I: Interpolate<L::Field>,
+{
+    for (entity, mut lens, mut component) in query.iter_mut() {
+        if lens.start.is_none() {
+            let current = L::field(component.bypass_change_detection()).clone();
+            lens.start = Some(EaseValue(current));
+        }
+        let (eased, step) = lens.advance::<I>(delta, settings);
+        if let Some(eased) = eased {
+            let field = L::field(&mut component);
+            *field = lens.blend_into::<I>(std::mem::take(field), eased, settings);
+        }
+        if let Some(step) = step {
+            if matches!(step, EasingStep::Completed(_)) {
+                commands.entity(entity).remove::<EasingLens<L>>();
+            }
+            send_step_event::<L>(commands, entity, step, lens.direction, 0);
+        }
+    }
+}
+
+/// Drive all sets of easings running at the same time on `C`, interpolating with `I`.
+///
+/// The eased values of all running easings are combined in order, and written once to the component.
+pub(crate) fn drive_tracks<C, I>(
+    commands: &mut Commands,
+    delta: Duration,
+    query: &mut Query<(Entity, &mut EasingTracks<C>, &mut C)>,
+    settings: &EaseSettings,
+) where
+    C: Component<Mutability = Mutable> + Default,
+    I: Interpolate<C>,
+{
+    for (entity, mut tracks, mut object) in query.iter_mut() {
+        let mut value = None;
+        let mut completed = Vec::new();
+        for (index, track) in tracks.0.iter_mut().enumerate() {
+            let (eased, step) = track.advance::<I>(delta, settings);
+            if let Some(eased) = eased {
+                value = Some(match value {
+                    Some(below) => track.blend_over::<I>(below, eased, settings),
+                    None => track.blend_into::<I>(std::mem::take(&mut *object), eased, settings),
+                });
+            }
+            match step {
+                Some(EasingStep::Completed(_)) => completed.push(index),
+                Some(step) => {
+                    send_step_event::<EasingTracks<C>>(commands, entity, step, track.direction, 0)
+                }
+                None => (),
+            }
+        }
+        if let Some(value) = value {
+            *object = value;
+        }
+        for index in completed.into_iter().rev() {
+            tracks.0.remove(index);
+        }
+        if tracks.0.is_empty() {
+            commands.entity(entity).remove::<EasingTracks<C>>();
+            send_easing_event(commands, EasingCompleted::<EasingTracks<C>>::new(entity));
+        }
+    }
+}
+
+/// Drive all tracks of keyframes on `C`, interpolating with `I`.
+pub(crate) fn drive_keyframes<C, I>(
+    commands: &mut Commands,
+    delta: Duration,
+    query: &mut Query<(Entity, &mut EasingKeyframes<C>, &mut C)>,
+    settings: &EaseSettings,
+) where
+    C: Component<Mutability = Mutable>,
+    I: Interpolate<C>,
+{
+    for (entity, mut keyframes, mut object) in query.iter_mut() {
+        if keyframes.state == EasingState::Paused && !std::mem::take(&mut keyframes.seeked) {
+            continue;
+        }
+        let step = keyframes.advance(delta);
+        if let Some(value) = keyframes.sample_with::<I>(keyframes.elapsed(), settings) {
+            *object = value;
+        }
+        if let Some((step, periods)) = step {
+            if matches!(step, EasingStep::Completed(_)) {
+                commands.entity(entity).remove::<EasingKeyframes<C>>();
+                send_step_event::<EasingKeyframes<C>>(
+                    commands,
+                    entity,
+                    step,
+                    keyframes.direction(),
+                    0,
+                );
+                continue;
+            }
+            // one event per time the track reached its end, each with the direction it took then
+            let reversed = matches!(step, EasingStep::Reversed);
+            let mut direction = keyframes.direction();
+            if reversed && periods % 2 == 1 {
+                direction.reverse();
+            }
+            for _ in 0..periods {
+                if reversed {
+                    direction.reverse();
+                }
+                send_step_event::<EasingKeyframes<C>>(commands, entity, step, direction, 0);
+            }
+        }
+    }
+}
+
+/// Send the event matching `step` for `entity`, with `chained` the number of easings still waiting in a chain.
+fn send_step_event<M: Send + Sync + 'static>(
+    commands: &mut Commands,
+    entity: Entity,
+    step: EasingStep,
+    direction: EasingDirection,
+    chained: usize,
+) {
+    match step {
+        EasingStep::Completed(_) if chained > 0 => send_easing_event(
+            commands,
+            EasingChainStepCompleted::<M>::new(entity, chained),
+        ),
+        EasingStep::Completed(_) => send_easing_event(commands, EasingCompleted::<M>::new(entity)),
+        EasingStep::LoopCompleted => {
+            send_easing_event(commands, EasingLoopCompleted::<M>::new(entity));
+        }
+        EasingStep::Reversed => send_easing_event(
+            commands,
+            EasingPingPongReversed::<M>::new(entity, direction),
+        ),
+    }
+}
@@ -0,0 +1,162 @@
+use std::marker::PhantomData;
+
+use bevy_ecs::{
+    entity::Entity,
+    event::EntityEvent,
+    message::{Message, Messages},
+    system::Commands,
+    world::World,
+};
+
+use crate::EasingDirection;
+
+/// Triggered when an [`EasingComponent`](crate::EasingComponent) with an [`EasingType::Once`](crate::EasingType::Once)
+/// finished easing, or when the last easing of an [`EasingChainComponent`](crate::EasingChainComponent) finished.
+///
+/// It is not triggered when the easing is removed before its end.
+#[derive(EntityEvent, Message)]
+pub struct EasingCompleted<C> {
+    /// The entity that was eased
+    pub entity: Entity,
+    _marker: PhantomData<fn() -> C>,
+}
+
+/// Triggered each time an [`EasingType::Loop`](crate::EasingType::Loop) easing reached its end and restarts.
+#[derive(EntityEvent, Message)]
+pub struct EasingLoopCompleted<C> {
+    /// The entity that was eased
+    pub entity: Entity,
+    _marker: PhantomData<fn() -> C>,
+}
+
+/// Triggered each time an [`EasingType::PingPong`](crate::EasingType::PingPong) easing reached an end and turns around.
+#[derive(EntityEvent, Message)]
+pub struct EasingPingPongReversed<C> {
+    /// The entity that was eased
+    pub entity: Entity,
+    /// The new direction of the easing
+    pub direction: EasingDirection,
+    _marker: PhantomData<fn() -> C>,
+}
+
+/// Triggered when an easing of an [`EasingChainComponent`](crate::EasingChainComponent) finished and another one
+/// is waiting in the chain.
+#[derive(EntityEvent, Message)]
+pub struct EasingChainStepCompleted<C> {
+    /// The entity that was eased
+    pub entity: Entity,
+    /// Number of easings still waiting in the chain
+    pub remaining: usize,
+    _marker: PhantomData<fn() -> C>,
+}
+
+impl<C> EasingCompleted<C> {
+    pub(crate) fn new(entity: Entity) -> Self {
+        Self {
+            entity,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<C> EasingLoopCompleted<C> {
+    pub(crate) fn new(entity: Entity) -> Self {
+        Self {
+            entity,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<C> EasingPingPongReversed<C> {
+    pub(crate) fn new(entity: Entity, direction: EasingDirection) -> Self {
+        Self {
+            entity,
+            direction,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<C> EasingChainStepCompleted<C> {
+    pub(crate) fn new(entity: Entity, remaining: usize) -> Self {
+        Self {
+            entity,
+            remaining,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<C> Clone for EasingCompleted<C> {
+    fn clone(&self) -> Self {
+        Self::new(self.entity)
+    }
+}
+
+impl<C> Clone for EasingLoopCompleted<C> {
+    fn clone(&self) -> Self {
+        Self::new(self.entity)
+    }
+}
+
+impl<C> Clone for EasingPingPongReversed<C> {
+    fn clone(&self) -> Self {
+        Self::new(self.entity, self.direction)
+    }
+}
+
+impl<C> Clone for EasingChainStepCompleted<C> {
+    fn clone(&self) -> Self {
+        Self::new(self.entity, self.remaining)
+    }
+}
+
+impl<C> std::fmt::Debug for EasingCompleted<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EasingCompleted")
+            .field("entity", &self.entity)
+            .finish()
+    }
+}
+
+impl<C> std::fmt::Debug for EasingLoopCompleted<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EasingLoopCompleted")
+            .field("entity", &self.entity)
+            .finish()
+    }
+}
+
+impl<C> std::fmt::Debug for EasingPingPongReversed<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EasingPingPongReversed")
+            .field("entity", &self.entity)
+            .field("direction", &self.direction)
+            .finish()
+    }
+}
+
+impl<C> std::fmt::Debug for EasingChainStepCompleted<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EasingChainStepCompleted")
+            .field("entity", &self.entity)
+            .field("remaining", &self.remaining)
+            .finish()
+    }
+}
+
+/// Trigger `event` for observers, and write it as a message if it has been registered with
+/// [`App::add_message`](bevy_app::App::add_message).
+pub(crate) fn send_easing_event<E>(commands: &mut Commands, event: E)
+where
+    E: EntityEvent + Message + Clone,
+    for<'t> E::Trigger<'t>: Default,
+{
+    commands.queue(move |world: &mut World| {
+        if let Some(mut messages) = world.get_resource_mut::<Messages<E>>() {
+            messages.write(event.clone());
+        }
+        world.trigger(event);
+    });
+}
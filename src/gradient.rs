@@ -0,0 +1,204 @@
+use std::sync::Arc;
+
+use bevy_color::Color;
+
+use crate::{
+    ColorInterpolation, ColorMix, Ease, EaseMethod, EasingComponent, EasingType, LerpColor,
+    driver::EaseSettings,
+};
+
+/// A color to reach at a position of a [`ColorGradient`]
+#[derive(Clone)]
+struct ColorStop {
+    position: f32,
+    color: Color,
+    ease_method: EaseMethod,
+}
+
+/// Colors placed along a gradient, to ease a color through several stops with
+/// [`GradientTarget::ease_gradient`].
+///
+/// Each stop has a position between 0 and 1, the color at that position, and the [`EaseMethod`] used to reach
+/// it from the previous stop. The color is held before the first stop and after the last one.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_easings::{ColorGradient, ColorInterpolation, EaseFunction, EaseMethod, EasingType, GradientTarget};
+/// use std::time::Duration;
+///
+/// fn system(mut commands: Commands) {
+///     let gradient = ColorGradient::new()
+///         .stop(0., Color::srgb(0., 1., 0.), EaseMethod::Linear)
+///         .stop(0.5, Color::srgb(1., 1., 0.), EaseFunction::QuadraticIn)
+///         .stop(1., Color::srgb(1., 0., 0.), EaseFunction::QuadraticOut)
+///         .space(ColorInterpolation::Oklab);
+///     commands.spawn((
+///         BackgroundColor(Color::srgb(0., 1., 0.)),
+///         BackgroundColor(Color::srgb(0., 1., 0.)).ease_gradient(
+///             gradient,
+///             EaseMethod::Linear,
+///             EasingType::Once { duration: Duration::from_secs(2) },
+///         ),
+///     ));
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct ColorGradient {
+    stops: Vec<ColorStop>,
+    mix: Option<ColorMix>,
+    space: Option<ColorInterpolation>,
+}
+
+impl ColorGradient {
+    /// Create an empty gradient
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a stop with `color` at `position`, following `ease_method` from the previous stop
+    pub fn stop(
+        mut self,
+        position: f32,
+        color: impl Into<Color>,
+        ease_method: impl Into<EaseMethod>,
+    ) -> Self {
+        let index = self.stops.partition_point(|stop| stop.position <= position);
+        self.stops.insert(
+            index,
+            ColorStop {
+                position,
+                color: color.into(),
+                ease_method: ease_method.into(),
+            },
+        );
+        self
+    }
+
+    /// Interpolate between stops with `mix` instead of the [`ColorMix`] resource
+    pub fn color_mix(mut self, mix: ColorMix) -> Self {
+        self.mix = Some(mix);
+        self
+    }
+
+    /// Interpolate between stops in `space`, keeping the other settings of the [`ColorMix`] of the gradient, or of the
+    /// one it is sampled with
+    pub fn space(mut self, space: ColorInterpolation) -> Self {
+        self.space = Some(space);
+        self
+    }
+
+    /// Color of the gradient at `position`, or [`Color::NONE`] if it has no stops.
+    ///
+    /// Stops are interpolated with `mix`, usually the [`ColorMix`] resource, unless the gradient has its own.
+    pub fn sample(&self, position: f32, mix: &ColorMix) -> Color {
+        let index = self.stops.partition_point(|stop| stop.position <= position);
+        let (from, to) = match index {
+            0 => return self.stops.first().map_or(Color::NONE, |stop| stop.color),
+            index if index == self.stops.len() => return self.stops[index - 1].color,
+            index => (&self.stops[index - 1], &self.stops[index]),
+        };
+        let progress = (position - from.position) / (to.position - from.position);
+        let mut mix = self.mix.unwrap_or(*mix);
+        if let Some(space) = self.space {
+            mix.space = space;
+        }
+        from.color
+            .lerp_color(&to.color, to.ease_method.factor(progress), &mix)
+    }
+}
+
+impl std::fmt::Debug for ColorGradient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ColorGradient")
+            .field(
+                "stops",
+                &self
+                    .stops
+                    .iter()
+                    .map(|stop| (stop.position, stop.color))
+                    .collect::<Vec<_>>(),
+            )
+            .field("mix", &self.mix)
+            .field("space", &self.space)
+            .finish()
+    }
+}
+
+/// Components with a color that can follow a [`ColorGradient`]
+pub trait GradientTarget: Ease + Send + Sync + 'static {
+    /// This component with its color replaced by `color`
+    fn with_color(&self, color: Color) -> Self;
+
+    /// Create a new easing moving the color of the component through `gradient`. Its other fields keep their
+    /// current values.
+    fn ease_gradient(
+        self,
+        gradient: ColorGradient,
+        ease_function: impl Into<EaseMethod>,
+        easing_type: EasingType,
+    ) -> EasingComponent<Self> {
+        let end = self.with_color(gradient.sample(1., &ColorMix::new()));
+        let mut easing = self.ease_to(end, ease_function, easing_type);
+        easing.interpolation = Some(Arc::new(
+            move |start: &Self, _end: &Self, factor, settings: &EaseSettings| {
+                start.with_color(gradient.sample(factor, &settings.color_mix))
+            },
+        ));
+        easing
+    }
+}
+
+impl GradientTarget for Color {
+    fn with_color(&self, color: Color) -> Self {
+        color
+    }
+}
+
+#[cfg(feature = "sprite")]
+impl GradientTarget for bevy_sprite::Sprite {
+    fn with_color(&self, color: Color) -> Self {
+        bevy_sprite::Sprite {
+            color,
+            ..self.clone()
+        }
+    }
+}
+
+#[cfg(all(feature = "ui", feature = "render"))]
+impl GradientTarget for bevy_ui::BackgroundColor {
+    fn with_color(&self, color: Color) -> Self {
+        bevy_ui::BackgroundColor(color)
+    }
+}
+
+#[cfg(all(feature = "ui", feature = "render"))]
+impl GradientTarget for bevy_ui::BorderColor {
+    fn with_color(&self, color: Color) -> Self {
+        bevy_ui::BorderColor::all(color)
+    }
+}
+
+#[cfg(all(feature = "ui", feature = "render"))]
+impl GradientTarget for bevy_ui::Outline {
+    fn with_color(&self, color: Color) -> Self {
+        bevy_ui::Outline { color, ..*self }
+    }
+}
+
+#[cfg(all(feature = "ui", feature = "render"))]
+impl GradientTarget for bevy_ui::widget::ImageNode {
+    fn with_color(&self, color: Color) -> Self {
+        bevy_ui::widget::ImageNode {
+            color,
+            ..self.clone()
+        }
+    }
+}
+
+#[cfg(feature = "ui")]
+impl GradientTarget for bevy_text::TextColor {
+    fn with_color(&self, color: Color) -> Self {
+        bevy_text::TextColor(color)
+    }
+}
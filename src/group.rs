@@ -0,0 +1,367 @@
+use std::{any::Any, time::Duration};
+
+use bevy_ecs::{
+    change_detection::DetectChangesMut,
+    component::{Component, Mutable},
+    world::EntityMutExcept,
+};
+
+use crate::{
+    EaseValue, EasingComponent, EasingLens, EasingState, Lens,
+    driver::{EaseSettings, EasingStep, Interpolate},
+    plugin::EasingsRegistry,
+};
+
+/// Advance a type-erased easing on an entity, returning the time left over once it completed
+pub(crate) type GroupRunner = fn(
+    &mut (dyn Any + Send + Sync),
+    &mut EntityMutExcept<EasingGroup>,
+    Duration,
+    &EaseSettings,
+) -> Option<Duration>;
+
+/// A step of an [`EasingGroup`]: an easing, a pause, or a composition of steps.
+///
+/// It can be created from an [`EasingComponent`] or an [`EasingLens`] of a registered component, or from a
+/// [`Sequence`], a [`Parallel`] or a [`Stagger`].
+pub struct GroupStep(Node);
+
+enum Node {
+    Easing {
+        easing: Box<dyn Any + Send + Sync>,
+        name: &'static str,
+    },
+    Wait(Duration),
+    Sequence(Vec<Node>),
+    Parallel(Vec<(Node, bool)>),
+}
+
+impl GroupStep {
+    /// A step doing nothing for `duration`
+    pub fn wait(duration: Duration) -> Self {
+        GroupStep(Node::Wait(duration))
+    }
+}
+
+impl<C: Component> From<EasingComponent<C>> for GroupStep {
+    fn from(easing: EasingComponent<C>) -> Self {
+        GroupStep(Node::Easing {
+            easing: Box::new(easing),
+            name: std::any::type_name::<C>(),
+        })
+    }
+}
+
+impl<L: Lens> From<EasingLens<L>> for GroupStep {
+    fn from(easing: EasingLens<L>) -> Self {
+        GroupStep(Node::Easing {
+            easing: Box::new(easing),
+            name: std::any::type_name::<L>(),
+        })
+    }
+}
+
+/// Steps played one after the other.
+#[derive(Default)]
+pub struct Sequence(Vec<Node>);
+
+impl Sequence {
+    /// Create an empty sequence
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a step, played once the previous ones finished
+    pub fn then(mut self, step: impl Into<GroupStep>) -> Self {
+        self.0.push(step.into().0);
+        self
+    }
+}
+
+/// Steps played at the same time, finished once all of them finished.
+#[derive(Default)]
+pub struct Parallel(Vec<Node>);
+
+impl Parallel {
+    /// Create an empty set of steps
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a step, played with the other ones
+    pub fn with(mut self, step: impl Into<GroupStep>) -> Self {
+        self.0.push(step.into().0);
+        self
+    }
+}
+
+/// Steps played at the same time, each one starting a fixed delay after the previous one.
+pub struct Stagger {
+    delay: Duration,
+    steps: Vec<Node>,
+}
+
+impl Stagger {
+    /// Create an empty set of steps, each one starting `delay` after the previous one
+    pub fn new(delay: Duration) -> Self {
+        Self {
+            delay,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Add a step, starting after the previous ones
+    pub fn with(mut self, step: impl Into<GroupStep>) -> Self {
+        self.steps.push(step.into().0);
+        self
+    }
+}
+
+impl From<Sequence> for GroupStep {
+    fn from(sequence: Sequence) -> Self {
+        GroupStep(Node::Sequence(sequence.0))
+    }
+}
+
+impl From<Parallel> for GroupStep {
+    fn from(parallel: Parallel) -> Self {
+        GroupStep(Node::Parallel(
+            parallel.0.into_iter().map(|node| (node, false)).collect(),
+        ))
+    }
+}
+
+impl From<Stagger> for GroupStep {
+    fn from(stagger: Stagger) -> Self {
+        let mut delay = Duration::ZERO;
+        GroupStep(Node::Parallel(
+            stagger
+                .steps
+                .into_iter()
+                .map(|node| {
+                    let node = Node::Sequence(vec![Node::Wait(delay), node]);
+                    delay += stagger.delay;
+                    (node, false)
+                })
+                .collect(),
+        ))
+    }
+}
+
+impl Node {
+    /// Advance this step by `delta`, returning the time left over once it finished
+    fn run(
+        &mut self,
+        registry: &EasingsRegistry,
+        entity: &mut EntityMutExcept<EasingGroup>,
+        delta: Duration,
+        settings: &EaseSettings,
+    ) -> Option<Duration> {
+        match self {
+            Node::Easing { easing, name } => match registry.runner((**easing).type_id()) {
+                Some(runner) => runner(easing.as_mut(), entity, delta, settings),
+                None => {
+                    bevy_log::warn!(
+                        "An easing on `{name}` was added to an `EasingGroup`, but `{name}` has not been registered \
+                        for easing. It is skipped."
+                    );
+                    Some(delta)
+                }
+            },
+            Node::Wait(remaining) => {
+                if delta >= *remaining {
+                    Some(delta - std::mem::take(remaining))
+                } else {
+                    *remaining -= delta;
+                    None
+                }
+            }
+            Node::Sequence(steps) => {
+                let mut delta = delta;
+                while !steps.is_empty() {
+                    delta = steps[0].run(registry, entity, delta, settings)?;
+                    steps.remove(0);
+                }
+                Some(delta)
+            }
+            Node::Parallel(steps) => {
+                // the group finishes with the last of its steps, so it has the least time left over
+                let mut running = false;
+                let mut left_over = delta;
+                for (step, finished) in steps.iter_mut().filter(|(_, finished)| !*finished) {
+                    match step.run(registry, entity, delta, settings) {
+                        Some(step_left_over) => {
+                            *finished = true;
+                            left_over = left_over.min(step_left_over);
+                        }
+                        None => running = true,
+                    }
+                }
+                (!running).then_some(left_over)
+            }
+        }
+    }
+}
+
+/// Component to play easings of different components of an entity together, built from a [`Sequence`],
+/// a [`Parallel`] or a [`Stagger`] of easings.
+///
+/// Easings of a group are driven by the group and not inserted on the entity. The component is removed once all
+/// the easings finished.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_easings::{Ease, EaseFunction, EasingGroup, EasingType, Parallel, Sequence};
+/// use std::time::Duration;
+///
+/// fn system(mut commands: Commands) {
+///     let once = EasingType::Once { duration: Duration::from_millis(500) };
+///     let transform = Transform::default();
+///     let sprite = Sprite::default();
+///     commands.spawn((
+///         transform,
+///         sprite.clone(),
+///         EasingGroup::from(
+///             Sequence::new()
+///                 .then(
+///                     Parallel::new()
+///                         .with(transform.ease_to(
+///                             transform.with_scale(Vec3::splat(2.)),
+///                             EaseFunction::BackOut,
+///                             once,
+///                         ))
+///                         .with(sprite.clone().ease_to(
+///                             Sprite::from_color(Color::WHITE, Vec2::ONE),
+///                             EaseFunction::QuadraticIn,
+///                             once,
+///                         )),
+///                 )
+///                 .then(transform.with_scale(Vec3::splat(2.)).ease_to(
+///                     transform,
+///                     EaseFunction::QuadraticOut,
+///                     once,
+///                 )),
+///         ),
+///     ));
+/// }
+/// ```
+#[derive(Component)]
+pub struct EasingGroup {
+    root: Node,
+    /// Control if this group is played or not
+    pub state: EasingState,
+}
+
+impl EasingGroup {
+    /// Create a group playing `step`
+    pub fn new(step: impl Into<GroupStep>) -> Self {
+        Self {
+            root: step.into().0,
+            state: EasingState::Play,
+        }
+    }
+
+    /// Advance the group by `delta`, returning `true` once it finished
+    pub(crate) fn run(
+        &mut self,
+        registry: &EasingsRegistry,
+        entity: &mut EntityMutExcept<EasingGroup>,
+        delta: Duration,
+        settings: &EaseSettings,
+    ) -> bool {
+        self.state == EasingState::Play
+            && self.root.run(registry, entity, delta, settings).is_some()
+    }
+}
+
+impl<S: Into<GroupStep>> From<S> for EasingGroup {
+    fn from(step: S) -> Self {
+        Self::new(step)
+    }
+}
+
+impl std::fmt::Debug for EasingGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EasingGroup")
+            .field("root", &self.root)
+            .field("state", &self.state)
+            .finish()
+    }
+}
+
+impl std::fmt::Debug for GroupStep {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::fmt::Debug for Node {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Node::Easing { name, .. } => f.debug_tuple("Easing").field(name).finish(),
+            Node::Wait(duration) => f.debug_tuple("Wait").field(duration).finish(),
+            Node::Sequence(steps) => f.debug_tuple("Sequence").field(steps).finish(),
+            Node::Parallel(steps) => f
+                .debug_tuple("Parallel")
+                .field(&steps.iter().map(|(step, _)| step).collect::<Vec<_>>())
+                .finish(),
+        }
+    }
+}
+
+/// Run an [`EasingComponent`] of a group
+pub(crate) fn run_component<C, I>(
+    easing: &mut (dyn Any + Send + Sync),
+    entity: &mut EntityMutExcept<EasingGroup>,
+    delta: Duration,
+    settings: &EaseSettings,
+) -> Option<Duration>
+where
+    C: Component<Mutability = Mutable> + Default,
+    I: Interpolate<C>,
+{
+    let easing = easing.downcast_mut::<EasingComponent<C>>()?;
+    let Some(mut object) = entity.get_mut::<C>() else {
+        return Some(delta);
+    };
+    let (eased, step) = easing.advance::<I>(delta, settings);
+    if let Some(eased) = eased {
+        *object = easing.blend_into::<I>(std::mem::take(&mut object), eased, settings);
+    }
+    match step {
+        Some(EasingStep::Completed(left_over)) => Some(left_over),
+        _ => None,
+    }
+}
+
+/// Run an [`EasingLens`] of a group
+pub(crate) fn run_lens<L, I>(
+    easing: &mut (dyn Any + Send + Sync),
+    entity: &mut EntityMutExcept<EasingGroup>,
+    delta: Duration,
+    settings: &EaseSettings,
+) -> Option<Duration>
+where
+    L: Lens,
+    L::Field: Clone + Default,
+    I: Interpolate<L::Field>,
+{
+    let lens = easing.downcast_mut::<EasingLens<L>>()?;
+    let Some(mut component) = entity.get_mut::<L::Component>() else {
+        return Some(delta);
+    };
+    if lens.start.is_none() {
+        let current = L::field(component.bypass_change_detection()).clone();
+        lens.start = Some(EaseValue(current));
+    }
+    let (eased, step) = lens.advance::<I>(delta, settings);
+    if let Some(eased) = eased {
+        let field = L::field(&mut component);
+        *field = lens.blend_into::<I>(std::mem::take(field), eased, settings);
+    }
+    match step {
+        Some(EasingStep::Completed(left_over)) => Some(left_over),
+        _ => None,
+    }
+}
@@ -1,14 +1,14 @@
 #[cfg(feature = "color")]
-use bevy_color::{
-    Color, ColorToComponents, Hsla, Hsva, Hwba, Laba, Lcha, LinearRgba, Oklaba, Oklcha, Srgba, Xyza,
-};
-use bevy_math::Rect;
+use bevy_color::Color;
+use bevy_math::{Quat, Rect, Vec2, Vec3};
 use bevy_transform::components::Transform;
 #[cfg(feature = "ui")]
 use bevy_ui::Val;
 use interpolation::Lerp;
 
-use crate::EaseValue;
+use crate::{Additive, EaseValue};
+#[cfg(feature = "color")]
+use crate::{ColorMix, LerpColor};
 
 #[cfg(feature = "sprite")]
 impl Lerp for EaseValue<bevy_sprite::Sprite> {
@@ -48,6 +48,175 @@ impl Lerp for EaseValue<bevy_ui::BackgroundColor> {
     }
 }
 
+#[cfg(all(feature = "ui", feature = "render"))]
+impl Lerp for EaseValue<bevy_ui::BorderColor> {
+    type Scalar = f32;
+
+    fn lerp(&self, other: &Self, scalar: &Self::Scalar) -> Self {
+        EaseValue(bevy_ui::BorderColor {
+            top: EaseValue(self.0.top)
+                .lerp(&EaseValue(other.0.top), scalar)
+                .0,
+            right: EaseValue(self.0.right)
+                .lerp(&EaseValue(other.0.right), scalar)
+                .0,
+            bottom: EaseValue(self.0.bottom)
+                .lerp(&EaseValue(other.0.bottom), scalar)
+                .0,
+            left: EaseValue(self.0.left)
+                .lerp(&EaseValue(other.0.left), scalar)
+                .0,
+        })
+    }
+}
+
+#[cfg(all(feature = "ui", feature = "render"))]
+impl Lerp for EaseValue<bevy_ui::Outline> {
+    type Scalar = f32;
+
+    fn lerp(&self, other: &Self, scalar: &Self::Scalar) -> Self {
+        EaseValue(bevy_ui::Outline {
+            width: EaseValue(self.0.width)
+                .lerp(&EaseValue(other.0.width), scalar)
+                .0,
+            offset: EaseValue(self.0.offset)
+                .lerp(&EaseValue(other.0.offset), scalar)
+                .0,
+            color: EaseValue(self.0.color)
+                .lerp(&EaseValue(other.0.color), scalar)
+                .0,
+        })
+    }
+}
+
+#[cfg(all(feature = "ui", feature = "render"))]
+impl Lerp for EaseValue<bevy_ui::ShadowStyle> {
+    type Scalar = f32;
+
+    fn lerp(&self, other: &Self, scalar: &Self::Scalar) -> Self {
+        EaseValue(bevy_ui::ShadowStyle {
+            color: EaseValue(self.0.color)
+                .lerp(&EaseValue(other.0.color), scalar)
+                .0,
+            x_offset: EaseValue(self.0.x_offset)
+                .lerp(&EaseValue(other.0.x_offset), scalar)
+                .0,
+            y_offset: EaseValue(self.0.y_offset)
+                .lerp(&EaseValue(other.0.y_offset), scalar)
+                .0,
+            spread_radius: EaseValue(self.0.spread_radius)
+                .lerp(&EaseValue(other.0.spread_radius), scalar)
+                .0,
+            blur_radius: EaseValue(self.0.blur_radius)
+                .lerp(&EaseValue(other.0.blur_radius), scalar)
+                .0,
+        })
+    }
+}
+
+#[cfg(all(feature = "ui", feature = "render"))]
+impl Lerp for EaseValue<bevy_ui::BoxShadow> {
+    type Scalar = f32;
+
+    fn lerp(&self, other: &Self, scalar: &Self::Scalar) -> Self {
+        let shadows = self.0.0.len().max(other.0.0.len());
+        EaseValue(bevy_ui::BoxShadow(
+            (0..shadows)
+                .filter_map(|i| match (self.0.0.get(i), other.0.0.get(i)) {
+                    (None, None) => None,
+                    (None, Some(b)) => {
+                        Some(EaseValue(faded_shadow(b)).lerp(&EaseValue(*b), scalar).0)
+                    }
+                    (Some(a), None) => {
+                        Some(EaseValue(*a).lerp(&EaseValue(faded_shadow(a)), scalar).0)
+                    }
+                    (Some(a), Some(b)) => Some(EaseValue(*a).lerp(&EaseValue(*b), scalar).0),
+                })
+                .collect(),
+        ))
+    }
+}
+
+/// Invisible copy of `shadow`, used to fade in or out a shadow that has no counterpart
+#[cfg(all(feature = "ui", feature = "render"))]
+fn faded_shadow(shadow: &bevy_ui::ShadowStyle) -> bevy_ui::ShadowStyle {
+    use bevy_color::Alpha;
+
+    bevy_ui::ShadowStyle {
+        color: shadow.color.with_alpha(0.),
+        blur_radius: match shadow.blur_radius {
+            Val::Px(_) => Val::Px(0.),
+            Val::Percent(_) => Val::Percent(0.),
+            Val::Vw(_) => Val::Vw(0.),
+            Val::Vh(_) => Val::Vh(0.),
+            blur_radius => blur_radius,
+        },
+        ..*shadow
+    }
+}
+
+#[cfg(all(feature = "ui", feature = "render"))]
+impl Lerp for EaseValue<bevy_ui::widget::ImageNode> {
+    type Scalar = f32;
+
+    fn lerp(&self, other: &Self, scalar: &Self::Scalar) -> Self {
+        EaseValue(bevy_ui::widget::ImageNode {
+            color: EaseValue(self.0.color)
+                .lerp(&EaseValue(other.0.color), scalar)
+                .0,
+            rect: match (self.0.rect, other.0.rect) {
+                (None, None) => None,
+                (None, Some(b)) => Some(b),
+                (Some(a), None) => Some(a),
+                (Some(a), Some(b)) => Some(EaseValue(a).lerp(&EaseValue(b), scalar).0),
+            },
+            ..self.0.clone()
+        })
+    }
+}
+
+#[cfg(feature = "ui")]
+impl Lerp for EaseValue<bevy_ui::BorderRadius> {
+    type Scalar = f32;
+
+    fn lerp(&self, other: &Self, scalar: &Self::Scalar) -> Self {
+        EaseValue(bevy_ui::BorderRadius {
+            top_left: EaseValue(self.0.top_left)
+                .lerp(&EaseValue(other.0.top_left), scalar)
+                .0,
+            top_right: EaseValue(self.0.top_right)
+                .lerp(&EaseValue(other.0.top_right), scalar)
+                .0,
+            bottom_right: EaseValue(self.0.bottom_right)
+                .lerp(&EaseValue(other.0.bottom_right), scalar)
+                .0,
+            bottom_left: EaseValue(self.0.bottom_left)
+                .lerp(&EaseValue(other.0.bottom_left), scalar)
+                .0,
+        })
+    }
+}
+
+#[cfg(feature = "ui")]
+impl Lerp for EaseValue<bevy_ui::UiTransform> {
+    type Scalar = f32;
+
+    fn lerp(&self, other: &Self, scalar: &Self::Scalar) -> Self {
+        EaseValue(bevy_ui::UiTransform {
+            translation: bevy_ui::Val2 {
+                x: EaseValue(self.0.translation.x)
+                    .lerp(&EaseValue(other.0.translation.x), scalar)
+                    .0,
+                y: EaseValue(self.0.translation.y)
+                    .lerp(&EaseValue(other.0.translation.y), scalar)
+                    .0,
+            },
+            scale: self.0.scale.lerp(other.0.scale, *scalar),
+            rotation: self.0.rotation.slerp(other.0.rotation, *scalar),
+        })
+    }
+}
+
 impl Lerp for EaseValue<Transform> {
     type Scalar = f32;
 
@@ -159,94 +328,14 @@ impl Lerp for EaseValue<Val> {
     }
 }
 
+/// Colors are interpolated with the default [`ColorMix`]. Easings of colors registered with
+/// [`EasingsAppExt::register_color_easing`](crate::EasingsAppExt::register_color_easing) use the [`ColorMix`] resource.
 #[cfg(feature = "color")]
 impl Lerp for EaseValue<Color> {
     type Scalar = f32;
 
     fn lerp(&self, other: &Self, scalar: &Self::Scalar) -> Self {
-        let color = match (self.0, other.0) {
-            (Color::Srgba(color), other) => {
-                let lerped = Srgba::from_vec4(
-                    color
-                        .to_vec4()
-                        .lerp(Into::<Srgba>::into(other).to_vec4(), *scalar),
-                );
-                Color::Srgba(lerped)
-            }
-            (Color::LinearRgba(color), other) => {
-                let lerped = LinearRgba::from_vec4(
-                    color
-                        .to_vec4()
-                        .lerp(Into::<LinearRgba>::into(other).to_vec4(), *scalar),
-                );
-                Color::LinearRgba(lerped)
-            }
-            (Color::Hsla(color), other) => {
-                let lerped = Hsla::from_vec4(
-                    color
-                        .to_vec4()
-                        .lerp(Into::<Hsla>::into(other).to_vec4(), *scalar),
-                );
-                Color::Hsla(lerped)
-            }
-            (Color::Hsva(color), other) => {
-                let lerped = Hsva::from_vec4(
-                    color
-                        .to_vec4()
-                        .lerp(Into::<Hsva>::into(other).to_vec4(), *scalar),
-                );
-                Color::Hsva(lerped)
-            }
-            (Color::Hwba(color), other) => {
-                let lerped = Hwba::from_vec4(
-                    color
-                        .to_vec4()
-                        .lerp(Into::<Hwba>::into(other).to_vec4(), *scalar),
-                );
-                Color::Hwba(lerped)
-            }
-            (Color::Laba(color), other) => {
-                let lerped = Laba::from_vec4(
-                    color
-                        .to_vec4()
-                        .lerp(Into::<Laba>::into(other).to_vec4(), *scalar),
-                );
-                Color::Laba(lerped)
-            }
-            (Color::Lcha(color), other) => {
-                let lerped = Lcha::from_vec4(
-                    color
-                        .to_vec4()
-                        .lerp(Into::<Lcha>::into(other).to_vec4(), *scalar),
-                );
-                Color::Lcha(lerped)
-            }
-            (Color::Oklaba(color), other) => {
-                let lerped = Oklaba::from_vec4(
-                    color
-                        .to_vec4()
-                        .lerp(Into::<Oklaba>::into(other).to_vec4(), *scalar),
-                );
-                Color::Oklaba(lerped)
-            }
-            (Color::Oklcha(color), other) => {
-                let lerped = Oklcha::from_vec4(
-                    color
-                        .to_vec4()
-                        .lerp(Into::<Oklcha>::into(other).to_vec4(), *scalar),
-                );
-                Color::Oklcha(lerped)
-            }
-            (Color::Xyza(color), other) => {
-                let lerped = Xyza::from_vec4(
-                    color
-                        .to_vec4()
-                        .lerp(Into::<Xyza>::into(other).to_vec4(), *scalar),
-                );
-                Color::Xyza(lerped)
-            }
-        };
-        EaseValue(color)
+        EaseValue(self.0.lerp_color(&other.0, *scalar, &ColorMix::new()))
     }
 }
 
@@ -271,3 +360,85 @@ impl Lerp for EaseValue<Rect> {
         })
     }
 }
+
+impl Lerp for EaseValue<Vec2> {
+    type Scalar = f32;
+
+    fn lerp(&self, other: &Self, scalar: &Self::Scalar) -> Self {
+        EaseValue(self.0.lerp(other.0, *scalar))
+    }
+}
+
+impl Lerp for EaseValue<Vec3> {
+    type Scalar = f32;
+
+    fn lerp(&self, other: &Self, scalar: &Self::Scalar) -> Self {
+        EaseValue(self.0.lerp(other.0, *scalar))
+    }
+}
+
+impl Lerp for EaseValue<Quat> {
+    type Scalar = f32;
+
+    fn lerp(&self, other: &Self, scalar: &Self::Scalar) -> Self {
+        EaseValue(self.0.lerp(other.0, *scalar))
+    }
+}
+
+impl Additive for Transform {
+    fn add(&self, other: &Self) -> Self {
+        Transform {
+            translation: self.translation + other.translation,
+            rotation: other.rotation * self.rotation,
+            scale: self.scale + other.scale - Vec3::ONE,
+        }
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        Transform {
+            translation: self.translation - other.translation,
+            rotation: self.rotation * other.rotation.inverse(),
+            scale: self.scale - other.scale + Vec3::ONE,
+        }
+    }
+}
+
+impl Additive for f32 {
+    fn add(&self, other: &Self) -> Self {
+        self + other
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        self - other
+    }
+}
+
+impl Additive for Vec2 {
+    fn add(&self, other: &Self) -> Self {
+        *self + *other
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        *self - *other
+    }
+}
+
+impl Additive for Vec3 {
+    fn add(&self, other: &Self) -> Self {
+        *self + *other
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        *self - *other
+    }
+}
+
+impl Additive for Quat {
+    fn add(&self, other: &Self) -> Self {
+        *other * *self
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        *self * other.inverse()
+    }
+}
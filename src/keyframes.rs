@@ -0,0 +1,264 @@
+use std::time::Duration;
+
+use bevy_ecs::component::Component;
+use interpolation::Lerp;
+
+use crate::{
+    EaseMethod, EaseValue, EasingDirection, EasingState,
+    driver::{ComponentLerp, EaseSettings, EaseValueLerp, EasingStep, Interpolate},
+};
+
+/// How a track of keyframes repeats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum KeyframesMode {
+    Once,
+    Loop,
+    PingPong,
+}
+
+/// A value to reach at a time of an [`EasingKeyframes`]
+#[derive(Clone)]
+struct Keyframe<T> {
+    time: Duration,
+    value: EaseValue<T>,
+    ease_method: EaseMethod,
+}
+
+/// Component to ease a component through a track of keyframes.
+///
+/// Each keyframe has a time from the start of the track, the value to reach at that time, and the
+/// [`EaseMethod`] used to reach it from the previous keyframe. The value is held before the first keyframe.
+/// The whole track is evaluated each frame, with no gap between keyframes.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_easings::{EaseFunction, EaseMethod, EasingKeyframes};
+/// use std::time::Duration;
+///
+/// fn system(mut commands: Commands) {
+///     commands.spawn((
+///         Transform::default(),
+///         EasingKeyframes::new()
+///             .keyframe(Duration::ZERO, Transform::default(), EaseMethod::Linear)
+///             .keyframe(
+///                 Duration::from_millis(500),
+///                 Transform::from_xyz(100., 0., 0.),
+///                 EaseFunction::QuadraticOut,
+///             )
+///             .keyframe(
+///                 Duration::from_secs(2),
+///                 Transform::from_xyz(100., 100., 0.),
+///                 EaseFunction::BounceOut,
+///             )
+///             .ping_pong(),
+///     ));
+/// }
+/// ```
+#[derive(Component)]
+#[component(on_add = crate::plugin::check_registered::<T>)]
+pub struct EasingKeyframes<T> {
+    keyframes: Vec<Keyframe<T>>,
+    mode: KeyframesMode,
+    elapsed: Duration,
+    direction: EasingDirection,
+    pub(crate) seeked: bool,
+    /// Control if this track is played or not
+    pub state: EasingState,
+}
+
+impl<T> EasingKeyframes<T> {
+    /// Create an empty track of keyframes, played once
+    pub fn new() -> Self {
+        Self {
+            keyframes: Vec::new(),
+            mode: KeyframesMode::Once,
+            elapsed: Duration::ZERO,
+            direction: EasingDirection::Forward,
+            seeked: false,
+            state: EasingState::Play,
+        }
+    }
+
+    /// Add a keyframe reaching `value` at `time` from the start of the track, following `ease_method` from the
+    /// previous keyframe
+    pub fn keyframe(
+        mut self,
+        time: Duration,
+        value: T,
+        ease_method: impl Into<EaseMethod>,
+    ) -> Self {
+        let index = self
+            .keyframes
+            .partition_point(|keyframe| keyframe.time <= time);
+        self.keyframes.insert(
+            index,
+            Keyframe {
+                time,
+                value: EaseValue(value),
+                ease_method: ease_method.into(),
+            },
+        );
+        self
+    }
+
+    /// Restart the track from the start once finished
+    pub fn looping(mut self) -> Self {
+        self.mode = KeyframesMode::Loop;
+        self
+    }
+
+    /// Play the track back and forth
+    pub fn ping_pong(mut self) -> Self {
+        self.mode = KeyframesMode::PingPong;
+        self
+    }
+
+    /// Duration of the track, which is the time of its last keyframe
+    pub fn duration(&self) -> Duration {
+        self.keyframes
+            .last()
+            .map_or(Duration::ZERO, |keyframe| keyframe.time)
+    }
+
+    /// Current time in the track
+    pub fn elapsed(&self) -> Duration {
+        match self.direction {
+            EasingDirection::Forward => self.elapsed,
+            EasingDirection::Backward => self.duration().saturating_sub(self.elapsed),
+        }
+    }
+
+    /// Move to `time` in the track. The component will be updated the next time easings run, even if paused.
+    pub fn set_elapsed(&mut self, time: Duration) {
+        self.seeked = true;
+        let time = time.min(self.duration());
+        self.elapsed = match self.direction {
+            EasingDirection::Forward => time,
+            EasingDirection::Backward => self.duration() - time,
+        };
+    }
+
+    /// For a track played back and forth, gets the current direction
+    pub fn direction(&self) -> EasingDirection {
+        self.direction
+    }
+
+    /// Value of the track at `time`, or `None` if it has no keyframes
+    pub fn sample(&self, time: Duration) -> Option<T>
+    where
+        EaseValue<T>: Lerp<Scalar = f32>,
+    {
+        self.sample_with::<EaseValueLerp>(time, &EaseSettings::default())
+    }
+
+    /// Value of the track at `time` for a custom component, or `None` if it has no keyframes
+    pub fn sample_custom(&self, time: Duration) -> Option<T>
+    where
+        T: Lerp<Scalar = f32>,
+    {
+        self.sample_with::<ComponentLerp>(time, &EaseSettings::default())
+    }
+
+    pub(crate) fn sample_with<I: Interpolate<T>>(
+        &self,
+        time: Duration,
+        settings: &EaseSettings,
+    ) -> Option<T> {
+        let index = self
+            .keyframes
+            .partition_point(|keyframe| keyframe.time <= time);
+        // values are copied through the interpolation to not require `Clone`
+        let (from, to) = match index {
+            0 => {
+                let first = self.keyframes.first()?;
+                return Some(I::interpolate(&first.value, &first.value, 0., settings));
+            }
+            index if index == self.keyframes.len() => {
+                let last = &self.keyframes[index - 1];
+                return Some(I::interpolate(&last.value, &last.value, 1., settings));
+            }
+            index => (&self.keyframes[index - 1], &self.keyframes[index]),
+        };
+        let progress = (time - from.time).as_secs_f32() / (to.time - from.time).as_secs_f32();
+        Some(I::interpolate(
+            &from.value,
+            &to.value,
+            to.ease_method.factor(progress),
+            settings,
+        ))
+    }
+
+    /// Advance the track by `delta`, returning what happened when it reached its end and how many times it did
+    pub(crate) fn advance(&mut self, delta: Duration) -> Option<(EasingStep, u32)> {
+        if self.state == EasingState::Paused {
+            return None;
+        }
+        let duration = self.duration();
+        self.elapsed += delta;
+        if self.elapsed < duration {
+            return None;
+        }
+        if duration.is_zero() {
+            self.elapsed = Duration::ZERO;
+            return (self.mode == KeyframesMode::Once).then_some((EasingStep::Completed(delta), 1));
+        }
+        let periods = self.elapsed.as_nanos() / duration.as_nanos();
+        let wrapped = Duration::from_nanos((self.elapsed.as_nanos() % duration.as_nanos()) as u64);
+        let periods = u32::try_from(periods).unwrap_or(u32::MAX);
+        match self.mode {
+            KeyframesMode::Once => {
+                let overshoot = self.elapsed - duration;
+                self.elapsed = duration;
+                Some((EasingStep::Completed(overshoot), 1))
+            }
+            KeyframesMode::Loop => {
+                self.elapsed = wrapped;
+                Some((EasingStep::LoopCompleted, periods))
+            }
+            KeyframesMode::PingPong => {
+                self.elapsed = wrapped;
+                if periods % 2 == 1 {
+                    self.direction.reverse();
+                }
+                Some((EasingStep::Reversed, periods))
+            }
+        }
+    }
+}
+
+impl<T> Default for EasingKeyframes<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone> Clone for EasingKeyframes<T> {
+    fn clone(&self) -> Self {
+        Self {
+            keyframes: self.keyframes.clone(),
+            mode: self.mode,
+            elapsed: self.elapsed,
+            direction: self.direction,
+            seeked: self.seeked,
+            state: self.state,
+        }
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for EasingKeyframes<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EasingKeyframes")
+            .field(
+                "keyframes",
+                &self
+                    .keyframes
+                    .iter()
+                    .map(|keyframe| (keyframe.time, &keyframe.value))
+                    .collect::<Vec<_>>(),
+            )
+            .field("elapsed", &self.elapsed)
+            .field("state", &self.state)
+            .finish()
+    }
+}
@@ -0,0 +1,228 @@
+use std::ops::{Deref, DerefMut};
+
+#[cfg(any(
+    all(feature = "sprite", feature = "color"),
+    all(feature = "ui", feature = "color")
+))]
+use bevy_color::Color;
+use bevy_ecs::component::{Component, Mutable};
+use bevy_math::{Quat, Vec3};
+use bevy_transform::components::Transform;
+
+use crate::EasingComponent;
+
+/// Access to a field of a component, to ease only this field instead of the whole component.
+///
+/// Several easings with different lenses can run on the same component at the same time.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_easings::Lens;
+///
+/// struct TransformTranslationXLens;
+///
+/// impl Lens for TransformTranslationXLens {
+///     type Component = Transform;
+///     type Field = f32;
+///
+///     fn field(component: &mut Transform) -> &mut f32 {
+///         &mut component.translation.x
+///     }
+/// }
+/// ```
+pub trait Lens: Send + Sync + 'static {
+    /// Component containing the field
+    type Component: Component<Mutability = Mutable>;
+    /// Type of the field to ease
+    type Field: Send + Sync + 'static;
+
+    /// Get the field from the component
+    fn field(component: &mut Self::Component) -> &mut Self::Field;
+}
+
+/// Component to control an easing of a field of a component, accessed through the [`Lens`] `L`.
+///
+/// It can be created from an [`EasingComponent`] of the field type with [`EasingComponent::with_lens`].
+/// If the easing has no start value, it will start from the current value of the field.
+#[derive(Component)]
+#[component(on_add = crate::plugin::check_registered::<L>)]
+pub struct EasingLens<L: Lens> {
+    pub(crate) easing: EasingComponent<L::Field>,
+}
+
+impl<L: Lens> Deref for EasingLens<L> {
+    type Target = EasingComponent<L::Field>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.easing
+    }
+}
+
+impl<L: Lens> DerefMut for EasingLens<L> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.easing
+    }
+}
+
+impl<L: Lens> Clone for EasingLens<L>
+where
+    L::Field: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            easing: self.easing.clone(),
+        }
+    }
+}
+
+impl<L: Lens> std::fmt::Debug for EasingLens<L>
+where
+    L::Field: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EasingLens")
+            .field("easing", &self.easing)
+            .finish()
+    }
+}
+
+impl<T> EasingComponent<T> {
+    /// Ease only the field of a component accessed through `lens`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use bevy_easings::{Ease, EaseFunction, EasingType, TransformTranslationLens};
+    ///
+    /// fn system(mut commands: Commands) {
+    ///     commands.spawn((
+    ///         Transform::default(),
+    ///         Vec3::ZERO
+    ///             .ease_to(
+    ///                 Vec3::new(0.0, 100.0, 0.0),
+    ///                 EaseFunction::QuadraticInOut,
+    ///                 EasingType::Once { duration: std::time::Duration::from_secs(1) },
+    ///             )
+    ///             .with_lens(TransformTranslationLens),
+    ///     ));
+    /// }
+    /// ```
+    pub fn with_lens<L: Lens<Field = T>>(self, _lens: L) -> EasingLens<L> {
+        EasingLens { easing: self }
+    }
+}
+
+/// Lens to the [`Transform::translation`] field
+#[derive(Debug, Clone, Copy)]
+pub struct TransformTranslationLens;
+
+impl Lens for TransformTranslationLens {
+    type Component = Transform;
+    type Field = Vec3;
+
+    fn field(component: &mut Transform) -> &mut Vec3 {
+        &mut component.translation
+    }
+}
+
+/// Lens to the [`Transform::rotation`] field
+#[derive(Debug, Clone, Copy)]
+pub struct TransformRotationLens;
+
+impl Lens for TransformRotationLens {
+    type Component = Transform;
+    type Field = Quat;
+
+    fn field(component: &mut Transform) -> &mut Quat {
+        &mut component.rotation
+    }
+}
+
+/// Lens to the [`Transform::scale`] field
+#[derive(Debug, Clone, Copy)]
+pub struct TransformScaleLens;
+
+impl Lens for TransformScaleLens {
+    type Component = Transform;
+    type Field = Vec3;
+
+    fn field(component: &mut Transform) -> &mut Vec3 {
+        &mut component.scale
+    }
+}
+
+/// Lens to the [`Sprite::color`](bevy_sprite::Sprite::color) field
+#[cfg(all(feature = "sprite", feature = "color"))]
+#[derive(Debug, Clone, Copy)]
+pub struct SpriteColorLens;
+
+#[cfg(all(feature = "sprite", feature = "color"))]
+impl Lens for SpriteColorLens {
+    type Component = bevy_sprite::Sprite;
+    type Field = Color;
+
+    fn field(component: &mut bevy_sprite::Sprite) -> &mut Color {
+        &mut component.color
+    }
+}
+
+/// Lens to the [`Node::width`](bevy_ui::Node::width) field
+#[cfg(feature = "ui")]
+#[derive(Debug, Clone, Copy)]
+pub struct NodeWidthLens;
+
+#[cfg(feature = "ui")]
+impl Lens for NodeWidthLens {
+    type Component = bevy_ui::Node;
+    type Field = bevy_ui::Val;
+
+    fn field(component: &mut bevy_ui::Node) -> &mut bevy_ui::Val {
+        &mut component.width
+    }
+}
+
+/// Lens to the [`Node::height`](bevy_ui::Node::height) field
+#[cfg(feature = "ui")]
+#[derive(Debug, Clone, Copy)]
+pub struct NodeHeightLens;
+
+#[cfg(feature = "ui")]
+impl Lens for NodeHeightLens {
+    type Component = bevy_ui::Node;
+    type Field = bevy_ui::Val;
+
+    fn field(component: &mut bevy_ui::Node) -> &mut bevy_ui::Val {
+        &mut component.height
+    }
+}
+
+/// Lens to the color of a [`BackgroundColor`](bevy_ui::BackgroundColor)
+#[cfg(all(feature = "ui", feature = "color"))]
+#[derive(Debug, Clone, Copy)]
+pub struct BackgroundColorLens;
+
+#[cfg(all(feature = "ui", feature = "color"))]
+impl Lens for BackgroundColorLens {
+    type Component = bevy_ui::BackgroundColor;
+    type Field = Color;
+
+    fn field(component: &mut bevy_ui::BackgroundColor) -> &mut Color {
+        &mut component.0
+    }
+}
+
+/// Lens to the color of a [`TextColor`](bevy_text::TextColor)
+#[cfg(all(feature = "ui", feature = "color"))]
+#[derive(Debug, Clone, Copy)]
+pub struct TextColorLens;
+
+#[cfg(all(feature = "ui", feature = "color"))]
+impl Lens for TextColorLens {
+    type Component = bevy_text::TextColor;
+    type Field = Color;
+
+    fn field(component: &mut bevy_text::TextColor) -> &mut Color {
+        &mut component.0
+    }
+}
@@ -11,7 +11,7 @@
 #![allow(clippy::needless_doctest_main)]
 #![doc = include_str!("../README.md")]
 
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 
 use bevy_ecs::component::Component;
 use bevy_time::Timer;
@@ -21,8 +21,46 @@ pub use interpolation::EaseFunction;
 pub use interpolation::Lerp;
 
 mod plugin;
-pub use plugin::{EasingsLabel, EasingsPlugin, custom_ease_system};
+mod rotation;
+mod spring;
+mod stagger;
+mod timing;
+pub use plugin::{EasingTimeScale, EasingsAppExt, EasingsLabel, EasingsPlugin, custom_ease_system};
+pub use rotation::RotationMode;
+pub use stagger::{StaggerDelay, StaggerOrder};
+pub use timing::{ParseEaseMethodError, StepPosition};
+mod blend;
+#[cfg(feature = "color")]
+mod color;
+pub use blend::{Additive, EaseBlend, EasingTracks};
+#[cfg(feature = "color")]
+pub use color::{ColorInterpolation, ColorMix, HueInterpolation, LerpColor};
+#[cfg(feature = "color")]
+mod gradient;
+#[cfg(feature = "color")]
+pub use gradient::{ColorGradient, GradientTarget};
+mod driver;
+mod events;
+mod group;
+pub use group::{EasingGroup, GroupStep, Parallel, Sequence, Stagger};
 mod implemented;
+mod keyframes;
+pub use keyframes::EasingKeyframes;
+mod timeline;
+pub use timeline::{Timeline, TimelineEasing, TimelineTarget};
+mod lens;
+pub use events::{
+    EasingChainStepCompleted, EasingCompleted, EasingLoopCompleted, EasingPingPongReversed,
+};
+#[cfg(all(feature = "sprite", feature = "color"))]
+pub use lens::SpriteColorLens;
+#[cfg(all(feature = "ui", feature = "color"))]
+pub use lens::{BackgroundColorLens, TextColorLens};
+pub use lens::{
+    EasingLens, Lens, TransformRotationLens, TransformScaleLens, TransformTranslationLens,
+};
+#[cfg(feature = "ui")]
+pub use lens::{NodeHeightLens, NodeWidthLens};
 
 /// Wrapper around a type that can be eased.
 #[derive(Debug, Clone, Copy)]
@@ -52,6 +90,46 @@ pub enum EasingType {
     },
 }
 
+impl EasingType {
+    fn duration(&self) -> Duration {
+        match self {
+            EasingType::Once { duration }
+            | EasingType::Loop { duration, .. }
+            | EasingType::PingPong { duration, .. } => *duration,
+        }
+    }
+}
+
+/// How long an [`EasingType::Loop`] or [`EasingType::PingPong`] easing repeats before it completes
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub enum Repeat {
+    /// Repeat until the easing is removed
+    #[default]
+    Forever,
+    /// Play this number of iterations, each way of a `PingPong` counting as one. At least one iteration is played.
+    Times(u32),
+    /// Start no new iteration once this time elapsed since the first one started, pauses included
+    For(Duration),
+}
+
+/// How an easing reaches a new end value set with [`EasingComponent::retarget`] while it is running.
+///
+/// Easings always continue from their current value. Springs keep their current velocity, whatever the policy.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub enum RetargetPolicy {
+    /// Restart the easing toward the new end, lasting its full duration
+    #[default]
+    Restart,
+    /// Restart the easing toward the new end, lasting the time that was left to reach the previous one
+    KeepRemainingTime,
+    /// Continue from the current point of the easing, at the same pace, with the rest of the curve now leading to
+    /// the new end. The speed is kept when the new end is as far as the previous one.
+    KeepSpeed,
+    /// Restart the easing toward the new end, lasting the time that was left to reach the previous one, while
+    /// blending from the previous movement so that the current velocity is kept
+    BlendFromVelocity,
+}
+
 /// Control if an easing is played
 #[derive(PartialEq, Eq, Debug, Clone, Copy)]
 pub enum EasingState {
@@ -73,7 +151,7 @@ impl std::ops::Not for EasingState {
 }
 
 /// Describe how eased value should be computed
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 pub enum EaseMethod {
     /// Follow `EaseFunction`
     EaseFunction(EaseFunction),
@@ -83,6 +161,85 @@ pub enum EaseMethod {
     Discrete,
     /// Use a custom function to interpolate the value
     CustomFunction(fn(f32) -> f32),
+    /// Use a custom closure to interpolate the value, which can capture its configuration
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy_easings::EaseMethod;
+    ///
+    /// let overshoot = 1.7;
+    /// let method = EaseMethod::closure(move |x| x * x * ((overshoot + 1.) * x - overshoot));
+    /// ```
+    CustomClosure(Arc<dyn Fn(f32) -> f32 + Send + Sync>),
+    /// Follow a cubic Bézier curve from (0, 0) to (1, 1), like the CSS `cubic-bezier()` timing function
+    CubicBezier {
+        /// x coordinate of the first control point, between 0 and 1
+        x1: f32,
+        /// y coordinate of the first control point
+        y1: f32,
+        /// x coordinate of the second control point, between 0 and 1
+        x2: f32,
+        /// y coordinate of the second control point
+        y2: f32,
+    },
+    /// Jump between evenly spaced values, like the CSS `steps()` timing function
+    Steps {
+        /// Number of steps
+        steps: u32,
+        /// When the jumps happen
+        position: StepPosition,
+    },
+    /// Follow a damped spring attached to the end value.
+    ///
+    /// The duration of the [`EasingType`] is ignored, the easing lasts until the spring settles.
+    Spring {
+        /// Stiffness of the spring, higher is faster
+        stiffness: f32,
+        /// Damping of the spring, lower oscillates more around the end value
+        damping: f32,
+        /// Mass attached to the spring, higher is slower
+        mass: f32,
+    },
+    /// Follow a critically damped spring, smoothly reaching the end value without overshooting.
+    ///
+    /// The duration of the [`EasingType`] is ignored, the easing lasts until the spring settles.
+    SmoothDamp {
+        /// Approximate time to reach the end value
+        smooth_time: Duration,
+    },
+}
+
+impl EaseMethod {
+    /// Use a custom closure to interpolate the value
+    pub fn closure(function: impl Fn(f32) -> f32 + Send + Sync + 'static) -> Self {
+        EaseMethod::CustomClosure(Arc::new(function))
+    }
+
+    /// Easing factor at `progress` of a fixed duration, springs being scaled to settle at the end
+    fn factor(&self, progress: f32) -> f32 {
+        match self.spring() {
+            Some(_) if progress >= 1. => 1.,
+            Some(spring) => {
+                let duration = spring.settle_duration(0.).as_secs_f32();
+                spring.state(0., progress * duration).0
+            }
+            None => progress.compute(self),
+        }
+    }
+
+    fn spring(&self) -> Option<spring::Spring> {
+        match *self {
+            EaseMethod::Spring {
+                stiffness,
+                damping,
+                mass,
+            } => Some(spring::Spring::new(stiffness, damping, mass)),
+            EaseMethod::SmoothDamp { smooth_time } => {
+                Some(spring::Spring::smooth_damp(smooth_time))
+            }
+            _ => None,
+        }
+    }
 }
 
 #[allow(clippy::from_over_into)]
@@ -93,11 +250,11 @@ impl Into<EaseMethod> for EaseFunction {
 }
 
 trait MyEaser {
-    fn compute(self, function: EaseMethod) -> Self;
+    fn compute(self, function: &EaseMethod) -> Self;
 }
 impl MyEaser for f32 {
-    fn compute(self, function: EaseMethod) -> f32 {
-        match function {
+    fn compute(self, function: &EaseMethod) -> f32 {
+        match *function {
             EaseMethod::EaseFunction(function) => self.calc(function),
             EaseMethod::Linear => {
                 let delta = 0.01;
@@ -117,12 +274,23 @@ impl MyEaser for f32 {
                 }
             }
             EaseMethod::CustomFunction(function) => function(self),
+            EaseMethod::CustomClosure(ref function) => function(self),
+            EaseMethod::CubicBezier { x1, y1, x2, y2 } => {
+                timing::cubic_bezier(x1, y1, x2, y2, self)
+            }
+            EaseMethod::Steps { steps, position } => timing::steps(steps, position, self),
+            // springs depend on the elapsed time, they are computed by the easing
+            EaseMethod::Spring { .. } | EaseMethod::SmoothDamp { .. } => self,
         }
     }
 }
 
+/// Interpolation between the start and the end of an easing, replacing the [`Lerp`] implementation of the type
+type Interpolation<T> = Arc<dyn Fn(&T, &T, f32, &driver::EaseSettings) -> T + Send + Sync>;
+
 /// Component to control an easing
 #[derive(Component, Clone)]
+#[component(on_add = plugin::check_registered::<T>)]
 pub struct EasingComponent<T> {
     start: Option<EaseValue<T>>,
     end: EaseValue<T>,
@@ -133,10 +301,27 @@ pub struct EasingComponent<T> {
     paused: bool,
     easing_type: EasingType,
     direction: EasingDirection,
+    blend: blend::Blend<T>,
+    velocity: f32,
+    retarget: Option<(EaseValue<T>, RetargetPolicy)>,
+    /// Easing factor at the point the easing continued from after a retarget keeping its speed
+    factor_offset: f32,
+    fading: Option<driver::Fading<T>>,
+    toggled: bool,
+    seeked: bool,
+    repeat: Repeat,
+    iterations: u32,
+    played: Duration,
+    /// Interpolation used instead of the [`Lerp`] implementation of the type
+    interpolation: Option<Interpolation<T>>,
+    /// Playback speed of this easing, multiplying the time elapsed. A negative speed plays it backward.
+    ///
+    /// Delays and pauses always elapse forward.
+    pub speed: f32,
 }
 
 /// Direction of an easing. It can be backward with an [`EasingType::PingPong`]
-#[derive(PartialEq, Eq, Clone, Copy)]
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
 pub enum EasingDirection {
     /// Easing is moving forward
     Forward = 1,
@@ -154,7 +339,74 @@ impl EasingDirection {
 }
 
 impl<T> EasingComponent<T> {
-    /// For [EasingType::PingPong], gets the current direction as -1 or 1.
+    fn new(start: Option<T>, end: T, ease_function: EaseMethod, easing_type: EasingType) -> Self {
+        let mut easing = EasingComponent {
+            start: start.map(EaseValue),
+            end: EaseValue(end),
+            ease_function,
+            timer: Timer::new(easing_type.duration(), TimerMode::Once),
+            state: EasingState::Play,
+            paused: false,
+            easing_type,
+            direction: EasingDirection::Forward,
+            blend: blend::Blend::Override,
+            velocity: 0.,
+            retarget: None,
+            factor_offset: 0.,
+            fading: None,
+            toggled: false,
+            seeked: false,
+            repeat: Repeat::Forever,
+            iterations: 0,
+            played: Duration::ZERO,
+            interpolation: None,
+            speed: 1.,
+        };
+        easing.timer.set_duration(easing.duration());
+        easing
+    }
+
+    /// Duration of one iteration of the easing, which is the time to settle for springs
+    fn duration(&self) -> Duration {
+        self.ease_function
+            .spring()
+            .map_or(self.easing_type.duration(), |spring| {
+                spring.settle_duration(self.velocity)
+            })
+    }
+
+    /// Change the end value of this easing while it is running, continuing from its current value following
+    /// `policy`.
+    ///
+    /// Springs keep their current velocity, so that they can smoothly follow a moving target.
+    pub fn retarget(&mut self, end: T, policy: RetargetPolicy) {
+        self.retarget = Some((EaseValue(end), policy));
+    }
+
+    /// Ease back toward the value the current movement started from, from the current value and taking as long as
+    /// the movement took so far. Springs keep their current velocity.
+    ///
+    /// It has no effect while waiting for a delay or a pause.
+    pub fn toggle(&mut self) {
+        self.toggled = true;
+    }
+
+    /// Play the current movement backward from the current point, following the same curve.
+    ///
+    /// A `Once` easing played backward completes once it is back to its start value. It has no effect while waiting
+    /// for a delay or a pause.
+    pub fn reverse(&mut self) {
+        if self.paused {
+            return;
+        }
+        self.direction.reverse();
+        let elapsed = self.timer.elapsed();
+        self.timer
+            .set_elapsed(self.timer.duration().saturating_sub(elapsed));
+    }
+
+    /// Gets the current direction as -1 or 1, which changes with [EasingType::PingPong] or
+    /// [`EasingComponent::reverse`].
     ///
     /// Positive is in the direction of the "ping" (first iteration).
     pub fn direction(&self) -> EasingDirection {
@@ -167,6 +419,105 @@ impl<T> EasingComponent<T> {
         self.timer = Timer::new(duration, TimerMode::Once);
         self
     }
+
+    /// Set the playback speed of this easing, multiplying the time elapsed.
+    ///
+    /// A negative speed plays the easing backward, from its end if it has not started yet. A `Once` easing played
+    /// backward completes when it reaches its start.
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        if speed < 0. && !self.paused && self.timer.elapsed().is_zero() {
+            self.timer.set_elapsed(self.timer.duration());
+        }
+        self
+    }
+
+    /// Stop repeating an [`EasingType::Loop`] or [`EasingType::PingPong`] easing after `repeat`. It then completes
+    /// like an [`EasingType::Once`] easing, at the end of its last iteration.
+    pub fn repeat(mut self, repeat: Repeat) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    /// Number of iterations of the easing that completed
+    pub fn iterations(&self) -> u32 {
+        self.iterations
+    }
+
+    /// Progress in the current iteration of the easing, between 0 and 1. It is 0 during a delay or a pause.
+    pub fn progress(&self) -> f32 {
+        if self.paused {
+            0.
+        } else {
+            self.timer.fraction()
+        }
+    }
+
+    /// Move to `progress` in the current iteration of the easing, between 0 and 1.
+    ///
+    /// See [`EasingComponent::seek`].
+    pub fn set_progress(&mut self, progress: f32) {
+        // a toggle or a retarget can change how long the current iteration lasts
+        let duration = if self.paused {
+            self.duration()
+        } else {
+            self.timer.duration()
+        };
+        self.seek(duration.mul_f32(progress.clamp(0., 1.)));
+    }
+
+    /// Time elapsed in the current iteration of the easing. It is zero during a delay or a pause.
+    pub fn elapsed(&self) -> Duration {
+        if self.paused {
+            Duration::ZERO
+        } else {
+            self.timer.elapsed()
+        }
+    }
+
+    /// Time left before the end of the current iteration of the easing, including what is left of a delay or a pause
+    pub fn remaining(&self) -> Duration {
+        if self.paused {
+            self.timer.remaining() + self.duration()
+        } else {
+            self.timer.remaining()
+        }
+    }
+
+    /// Move to `time` in the current iteration of the easing, skipping what is left of a delay or a pause.
+    ///
+    /// The iterations already completed and the time already played still count toward the [`Repeat`] of the
+    /// easing. The component will be updated the next time easings run, even if paused.
+    pub fn seek(&mut self, time: Duration) {
+        if self.paused {
+            self.paused = false;
+            self.timer.set_duration(self.duration());
+        }
+        self.timer.set_elapsed(time.min(self.timer.duration()));
+        self.seeked = true;
+    }
+
+    /// Restart the easing from its start value, forward, skipping what is left of a delay. Its [`Repeat`] starts
+    /// over, with no iteration completed.
+    ///
+    /// The component will be updated the next time easings run, even if paused.
+    pub fn restart(&mut self) {
+        self.direction = EasingDirection::Forward;
+        self.iterations = 0;
+        self.played = Duration::ZERO;
+        self.seek(Duration::ZERO);
+    }
+
+    /// Remove the delay set with [`EasingComponent::delay`], returning it
+    fn take_delay(&mut self) -> Duration {
+        if !self.paused {
+            return Duration::ZERO;
+        }
+        let delay = self.timer.duration();
+        self.paused = false;
+        self.timer = Timer::new(self.duration(), TimerMode::Once);
+        delay
+    }
 }
 
 impl<T> EasingComponent<T>
@@ -187,6 +538,7 @@ impl<T: std::fmt::Debug> std::fmt::Debug for EasingComponent<T> {
             .field("start", &self.start)
             .field("end", &self.end)
             .field("state", &self.state)
+            .field("blend", &self.blend())
             .finish()
     }
 }
@@ -199,20 +551,7 @@ impl<T: Default> EasingComponent<T> {
         ease_function: impl Into<EaseMethod>,
         easing_type: EasingType,
     ) -> EasingChainComponent<T> {
-        let next = EasingComponent {
-            start: None,
-            end: EaseValue(end),
-            ease_function: ease_function.into(),
-            timer: match easing_type {
-                EasingType::Once { duration }
-                | EasingType::Loop { duration, .. }
-                | EasingType::PingPong { duration, .. } => Timer::new(duration, TimerMode::Once),
-            },
-            state: EasingState::Play,
-            paused: false,
-            easing_type,
-            direction: EasingDirection::Forward,
-        };
+        let next = EasingComponent::new(None, end, ease_function.into(), easing_type);
 
         EasingChainComponent(vec![next, self])
     }
@@ -254,6 +593,7 @@ impl<T: Default> EasingComponent<T> {
 
 /// Component to control a chain of easing
 #[derive(Component)]
+#[component(on_add = plugin::check_registered::<T>)]
 pub struct EasingChainComponent<T>(Vec<EasingComponent<T>>);
 
 impl<T: Default> EasingChainComponent<T> {
@@ -264,20 +604,7 @@ impl<T: Default> EasingChainComponent<T> {
         ease_function: impl Into<EaseMethod>,
         easing_type: EasingType,
     ) -> EasingChainComponent<T> {
-        let next = EasingComponent {
-            start: None,
-            end: EaseValue(end),
-            ease_function: ease_function.into(),
-            timer: match easing_type {
-                EasingType::Once { duration }
-                | EasingType::Loop { duration, .. }
-                | EasingType::PingPong { duration, .. } => Timer::new(duration, TimerMode::Once),
-            },
-            state: EasingState::Play,
-            paused: false,
-            easing_type,
-            direction: EasingDirection::Forward,
-        };
+        let next = EasingComponent::new(None, end, ease_function.into(), easing_type);
 
         self.0.insert(0, next);
         self
@@ -332,17 +659,11 @@ impl<T: Default> EasingChainComponent<T> {
     where
         T: Clone,
     {
-        if let Some(last_component) = self.0.last() {
-            let start_value = match &last_component.start {
-                Some(start) => start.0.clone(),
-                None => T::default(),
-            };
-            let target = target_fn(&start_value);
-            self.ease_to(target, ease_function, easing_type)
-        } else {
-            let target = target_fn(&T::default());
-            self.ease_to(target, ease_function, easing_type)
-        }
+        let target = match self.0.first() {
+            Some(previous) => target_fn(&previous.end.0),
+            None => target_fn(&T::default()),
+        };
+        self.ease_to(target, ease_function, easing_type)
     }
 
     /// Repeat the chain `n` times.
@@ -375,20 +696,7 @@ pub trait Ease: Sized {
         ease_function: impl Into<EaseMethod>,
         easing_type: EasingType,
     ) -> EasingComponent<Self> {
-        EasingComponent {
-            start: start.map(EaseValue),
-            end: EaseValue(end),
-            ease_function: ease_function.into(),
-            timer: match easing_type {
-                EasingType::Once { duration }
-                | EasingType::Loop { duration, .. }
-                | EasingType::PingPong { duration, .. } => Timer::new(duration, TimerMode::Once),
-            },
-            state: EasingState::Play,
-            paused: false,
-            easing_type,
-            direction: EasingDirection::Forward,
-        }
+        EasingComponent::new(start, end, ease_function.into(), easing_type)
     }
 
     /// Create a new easing with the current component value as a starting point
@@ -431,6 +739,39 @@ pub trait Ease: Sized {
         let target = target_fn(&self);
         Self::ease(Some(self), target, ease_function, easing_type)
     }
+
+    /// Create a new additive easing, moving the component by `delta` on top of its current value
+    ///
+    /// The component can still be changed by other systems while this easing is running, and
+    /// both changes are combined. `delta` is relative to the default value, which should not change
+    /// a value when added to it.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use bevy::prelude::*;
+    /// use bevy_easings::{Ease, EasingType, EaseFunction};
+    ///
+    /// fn system(mut commands: Commands) {
+    ///     commands.spawn((
+    ///         Transform::default(),
+    ///         Transform::ease_by(
+    ///             Transform::from_translation(Vec3::new(0.0, 100.0, 0.0)),
+    ///             EaseFunction::QuadraticInOut,
+    ///             EasingType::Once { duration: std::time::Duration::from_secs(1) },
+    ///         ),
+    ///     ));
+    /// }
+    /// ```
+    fn ease_by(
+        delta: Self,
+        ease_function: impl Into<EaseMethod>,
+        easing_type: EasingType,
+    ) -> EasingComponent<Self>
+    where
+        Self: Additive + Default,
+    {
+        Self::ease(Some(Self::default()), delta, ease_function, easing_type).additive()
+    }
 }
 
 impl<T> Ease for EaseValue<T> where T: Lerp<Scalar = f32> {}
@@ -454,20 +795,7 @@ pub trait CustomComponentEase: Sized {
         ease_function: impl Into<EaseMethod>,
         easing_type: EasingType,
     ) -> EasingComponent<Self> {
-        EasingComponent {
-            start: start.map(EaseValue),
-            end: EaseValue(end),
-            ease_function: ease_function.into(),
-            timer: match easing_type {
-                EasingType::Once { duration }
-                | EasingType::Loop { duration, .. }
-                | EasingType::PingPong { duration, .. } => Timer::new(duration, TimerMode::Once),
-            },
-            state: EasingState::Play,
-            paused: false,
-            easing_type,
-            direction: EasingDirection::Forward,
-        }
+        EasingComponent::new(start, end, ease_function.into(), easing_type)
     }
 
     /// Create a new easing with the current component value as a starting point
@@ -520,6 +848,58 @@ pub trait CustomComponentEase: Sized {
         let target = target_fn(&self);
         Self::ease(Some(self), target, ease_function, easing_type)
     }
+
+    /// Create a new additive easing, moving the component by `delta` on top of its current value
+    ///
+    /// The component can still be changed by other systems while this easing is running, and
+    /// both changes are combined. `delta` is relative to the default value, which should not change
+    /// a value when added to it.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use bevy::prelude::*;
+    /// use bevy_easings::{CustomComponentEase, EasingType, EaseFunction, Lerp};
+    ///
+    /// #[derive(Default, Component)]
+    /// struct CustomComponent(f32);
+    ///
+    /// impl Lerp for CustomComponent {
+    ///     type Scalar = f32;
+    ///     fn lerp(&self, other: &Self, scalar: &Self::Scalar) -> Self {
+    ///         CustomComponent(interpolation::lerp(&self.0, &other.0, scalar))
+    ///     }
+    /// }
+    ///
+    /// impl bevy_easings::Additive for CustomComponent {
+    ///     fn add(&self, other: &Self) -> Self {
+    ///         CustomComponent(self.0 + other.0)
+    ///     }
+    ///     fn sub(&self, other: &Self) -> Self {
+    ///         CustomComponent(self.0 - other.0)
+    ///     }
+    /// }
+    ///
+    /// fn system(mut commands: Commands) {
+    ///     commands.spawn((
+    ///         CustomComponent(5.0),
+    ///         CustomComponent::ease_by(
+    ///             CustomComponent(10.0),
+    ///             EaseFunction::QuadraticInOut,
+    ///             EasingType::Once { duration: std::time::Duration::from_secs(1) },
+    ///         ),
+    ///     ));
+    /// }
+    /// ```
+    fn ease_by(
+        delta: Self,
+        ease_function: impl Into<EaseMethod>,
+        easing_type: EasingType,
+    ) -> EasingComponent<Self>
+    where
+        Self: Additive + Default,
+    {
+        Self::ease(Some(Self::default()), delta, ease_function, easing_type).additive()
+    }
 }
 
 impl<T> CustomComponentEase for T where T: Lerp<Scalar = f32> {}
@@ -1,21 +1,45 @@
-use bevy_app::{App, Plugin, Update};
+use std::{
+    any::TypeId,
+    collections::{HashMap, HashSet},
+    time::Duration,
+};
+
+use bevy_app::{App, Last, Plugin, Update};
 use bevy_ecs::{
     component::{Component, Mutable},
     entity::Entity,
+    lifecycle::HookContext,
     query::With,
-    schedule::{IntoScheduleConfigs, SystemSet},
-    system::{Commands, Query, Res},
+    resource::Resource,
+    schedule::{
+        IntoScheduleConfigs, SystemCondition, SystemSet, common_conditions::any_with_component,
+    },
+    system::{Commands, Local, Query, Res, ResMut, SystemParam},
+    world::{DeferredWorld, EntityMutExcept, World},
 };
+use bevy_log::warn;
 use bevy_time::Time;
 use bevy_transform::components::Transform;
 
-use crate::{EasingDirection, MyEaser};
-
 use crate::{
-    CustomComponentEase, Ease, EaseValue, EasingChainComponent, EasingComponent, EasingState,
-    EasingType,
+    CustomComponentEase, Ease, EaseValue, EasingChainComponent, EasingComponent, EasingKeyframes,
+    EasingLens, EasingTracks, Lens, TransformRotationLens, TransformScaleLens,
+    TransformTranslationLens,
+    driver::{
+        ComponentLerp, EaseSettings, EaseValueLerp, Interpolate, drive_easings, drive_keyframes,
+        drive_lens_easings, drive_tracks,
+    },
+    events::{
+        EasingChainStepCompleted, EasingCompleted, EasingLoopCompleted, EasingPingPongReversed,
+        send_easing_event,
+    },
+    group::{EasingGroup, GroupRunner, run_component, run_lens},
+    timeline::{Timeline, TimelineSampler, sample_component, sample_lens},
 };
 
+#[cfg(feature = "color")]
+use crate::driver::ColorLerp;
+
 /// Plugin to add systems related to easing
 #[allow(clippy::module_name_repetitions)]
 #[derive(Debug, Clone, Copy)]
@@ -44,122 +68,479 @@ impl<T: Default> EasingsPlugin<T> {
 #[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone, Copy)]
 pub struct EasingsLabel;
 
+/// Systems easing any component, run before the systems easing a single component so that the order in which they
+/// write to the same components is fixed
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone, Copy)]
+struct ComposedEasings;
+
 impl<T: Default + Send + Sync + 'static> Plugin for EasingsPlugin<T> {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, ease_system::<T, Transform>.in_set(EasingsLabel));
-        #[cfg(feature = "sprite")]
-        app.add_systems(
-            Update,
-            ease_system::<T, bevy_sprite::Sprite>.in_set(EasingsLabel),
-        );
-        #[cfg(feature = "ui")]
-        app.add_systems(Update, ease_system::<T, bevy_ui::Node>.in_set(EasingsLabel));
+        app.init_resource::<EasingsRegistry>()
+            .init_resource::<EasingsDelta>()
+            .init_resource::<EasingTimeScale>()
+            .add_systems(Update, update_easings_delta::<T>.before(EasingsLabel))
+            .add_systems(
+                Update,
+                (
+                    ease_groups_system
+                        .in_set(ComposedEasings)
+                        .run_if(any_with_component::<EasingGroup>),
+                    ease_timelines_system
+                        .in_set(ComposedEasings)
+                        .after(ease_groups_system)
+                        .run_if(any_with_component::<Timeline>),
+                )
+                    .in_set(EasingsLabel),
+            )
+            .add_systems(Last, warn_unregistered_easings);
+        add_easing_messages::<EasingGroup>(app);
+        add_easing_messages::<Timeline>(app);
+        #[cfg(feature = "color")]
+        app.init_resource::<crate::ColorMix>();
+
+        app.register_easing::<Transform>()
+            .register_lens::<TransformTranslationLens>()
+            .register_lens::<TransformRotationLens>()
+            .register_lens::<TransformScaleLens>();
+        #[cfg(all(feature = "sprite", feature = "color"))]
+        app.register_color_easing::<bevy_sprite::Sprite>()
+            .register_color_lens::<crate::SpriteColorLens>();
+        #[cfg(all(feature = "sprite", not(feature = "color")))]
+        app.register_easing::<bevy_sprite::Sprite>();
         #[cfg(feature = "ui")]
+        app.register_easing::<bevy_ui::Node>()
+            .register_easing::<bevy_ui::BorderRadius>()
+            .register_easing::<bevy_ui::UiTransform>()
+            .register_lens::<crate::NodeWidthLens>()
+            .register_lens::<crate::NodeHeightLens>();
+        #[cfg(all(feature = "ui", feature = "color"))]
+        app.register_color_easing::<bevy_text::TextColor>()
+            .register_color_lens::<crate::BackgroundColorLens>()
+            .register_color_lens::<crate::TextColorLens>();
+        #[cfg(all(feature = "ui", feature = "render"))]
+        app.register_easing::<bevy_ui::BoxShadow>()
+            .register_color_easing::<bevy_ui::BackgroundColor>()
+            .register_color_easing::<bevy_ui::BorderColor>()
+            .register_color_easing::<bevy_ui::Outline>()
+            .register_color_easing::<bevy_ui::widget::ImageNode>();
+    }
+}
+
+/// Extension trait to register components that can be eased.
+///
+/// The systems are added in the [`EasingsLabel`] set, and use the `Time` selected with the [`EasingsPlugin`]. The
+/// plugin must be added before registering components, registering them without it panics.
+#[allow(clippy::module_name_repetitions)]
+pub trait EasingsAppExt {
+    /// Register a component `C` that can be eased, as `EaseValue<C>` implements [`Lerp`](interpolation::Lerp).
+    ///
+    /// Registering the same component more than once has no effect.
+    fn register_easing<C>(&mut self) -> &mut Self
+    where
+        C: Ease + Component<Mutability = Mutable> + Default,
+        EaseValue<C>: interpolation::Lerp<Scalar = f32>;
+
+    /// Register a custom component `C` that can be eased, as it implements [`Lerp`](interpolation::Lerp).
+    ///
+    /// Registering the same component more than once has no effect.
+    fn register_custom_easing<C>(&mut self) -> &mut Self
+    where
+        C: CustomComponentEase
+            + Component<Mutability = Mutable>
+            + interpolation::Lerp<Scalar = f32>
+            + Default;
+
+    /// Register a [`Lens`] to ease a field of a component, as `EaseValue<L::Field>` implements
+    /// [`Lerp`](interpolation::Lerp).
+    ///
+    /// Registering the same lens more than once has no effect.
+    fn register_lens<L>(&mut self) -> &mut Self
+    where
+        L: Lens,
+        L::Field: Clone + Default,
+        EaseValue<L::Field>: interpolation::Lerp<Scalar = f32>;
+
+    /// Register a [`Lens`] to ease a field of a component, as `L::Field` implements [`Lerp`](interpolation::Lerp).
+    ///
+    /// Registering the same lens more than once has no effect.
+    fn register_custom_lens<L>(&mut self) -> &mut Self
+    where
+        L: Lens,
+        L::Field: interpolation::Lerp<Scalar = f32> + Clone + Default;
+
+    /// Register a component `C` holding a color that can be eased, as it implements
+    /// [`LerpColor`](crate::LerpColor). Its colors are interpolated with the [`ColorMix`](crate::ColorMix) resource.
+    ///
+    /// Registering the same component more than once has no effect.
+    #[cfg(feature = "color")]
+    fn register_color_easing<C>(&mut self) -> &mut Self
+    where
+        C: Ease + Component<Mutability = Mutable> + crate::LerpColor + Default;
+
+    /// Register a [`Lens`] to ease a field holding a color, as `L::Field` implements
+    /// [`LerpColor`](crate::LerpColor). Its colors are interpolated with the [`ColorMix`](crate::ColorMix) resource.
+    ///
+    /// Registering the same lens more than once has no effect.
+    #[cfg(feature = "color")]
+    fn register_color_lens<L>(&mut self) -> &mut Self
+    where
+        L: Lens,
+        L::Field: crate::LerpColor + Clone + Default;
+}
+
+impl EasingsAppExt for App {
+    fn register_easing<C>(&mut self) -> &mut Self
+    where
+        C: Ease + Component<Mutability = Mutable> + Default,
+        EaseValue<C>: interpolation::Lerp<Scalar = f32>,
+    {
+        add_component_easing::<C, EaseValueLerp>(self)
+    }
+
+    fn register_custom_easing<C>(&mut self) -> &mut Self
+    where
+        C: CustomComponentEase
+            + Component<Mutability = Mutable>
+            + interpolation::Lerp<Scalar = f32>
+            + Default,
+    {
+        add_component_easing::<C, ComponentLerp>(self)
+    }
+
+    fn register_lens<L>(&mut self) -> &mut Self
+    where
+        L: Lens,
+        L::Field: Clone + Default,
+        EaseValue<L::Field>: interpolation::Lerp<Scalar = f32>,
+    {
+        add_lens_easing::<L, EaseValueLerp>(self)
+    }
+
+    fn register_custom_lens<L>(&mut self) -> &mut Self
+    where
+        L: Lens,
+        L::Field: interpolation::Lerp<Scalar = f32> + Clone + Default,
+    {
+        add_lens_easing::<L, ComponentLerp>(self)
+    }
+
+    #[cfg(feature = "color")]
+    fn register_color_easing<C>(&mut self) -> &mut Self
+    where
+        C: Ease + Component<Mutability = Mutable> + crate::LerpColor + Default,
+    {
+        add_component_easing::<C, ColorLerp>(self)
+    }
+
+    #[cfg(feature = "color")]
+    fn register_color_lens<L>(&mut self) -> &mut Self
+    where
+        L: Lens,
+        L::Field: crate::LerpColor + Clone + Default,
+    {
+        add_lens_easing::<L, ColorLerp>(self)
+    }
+}
+
+/// Add the systems easing the component `C`, interpolating with `I`, unless it was already registered.
+fn add_component_easing<C, I>(app: &mut App) -> &mut App
+where
+    C: Component<Mutability = Mutable> + Default,
+    I: Interpolate<C> + Send + Sync + 'static,
+{
+    if register::<C>(app) {
+        add_runners::<EasingComponent<C>>(app, run_component::<C, I>, sample_component::<C, I>);
+        add_easing_messages::<EasingTracks<C>>(app);
+        add_easing_messages::<EasingKeyframes<C>>(app);
         app.add_systems(
             Update,
-            ease_system::<T, bevy_ui::BackgroundColor>.in_set(EasingsLabel),
+            (
+                ease_system::<C, I>.run_if(
+                    any_with_component::<EasingComponent<C>>
+                        .or(any_with_component::<EasingChainComponent<C>>),
+                ),
+                ease_tracks_system::<C, I>.run_if(any_with_component::<EasingTracks<C>>),
+                ease_keyframes_system::<C, I>.run_if(any_with_component::<EasingKeyframes<C>>),
+            )
+                .in_set(EasingsLabel)
+                .after(ComposedEasings),
         );
-        #[cfg(feature = "ui")]
+    }
+    app
+}
+
+/// Add the systems easing the field of a component accessed through the lens `L`, interpolating with `I`, unless it
+/// was already registered.
+fn add_lens_easing<L, I>(app: &mut App) -> &mut App
+where
+    L: Lens,
+    L::Field: Clone + Default,
+    I: Interpolate<L::Field> + Send + Sync + 'static,
+{
+    if register::<L>(app) {
+        add_runners::<EasingLens<L>>(app, run_lens::<L, I>, sample_lens::<L, I>);
         app.add_systems(
             Update,
-            ease_system::<T, bevy_text::TextColor>.in_set(EasingsLabel),
+            ease_lens_system::<L, I>
+                .run_if(any_with_component::<EasingLens<L>>)
+                .in_set(EasingsLabel)
+                .after(ComposedEasings),
         );
     }
+    app
 }
 
-pub fn ease_system<
-    T: Default + Send + Sync + 'static,
-    C: Ease + Component<Mutability = Mutable> + Default,
->(
-    mut commands: Commands,
+/// Mark `C` (a component or a lens) as registered and add its messages. Returns `false` if it was already registered.
+///
+/// # Panics
+///
+/// Panics if the [`EasingsPlugin`] was not added, as it provides the time and settings used by the easing systems.
+fn register<C: Send + Sync + 'static>(app: &mut App) -> bool {
+    assert!(
+        app.world().contains_resource::<EasingsRegistry>(),
+        "`EasingsPlugin` must be added before registering easings for `{}`",
+        std::any::type_name::<C>()
+    );
+    if !app
+        .world_mut()
+        .resource_mut::<EasingsRegistry>()
+        .registered
+        .insert(TypeId::of::<C>())
+    {
+        return false;
+    }
+    add_easing_messages::<C>(app);
+    true
+}
+
+/// Add how to run easings of type `E` in an [`EasingGroup`] and in a [`Timeline`].
+fn add_runners<E: 'static>(app: &mut App, runner: GroupRunner, sampler: TimelineSampler) {
+    let mut registry = app.world_mut().resource_mut::<EasingsRegistry>();
+    registry.runners.insert(TypeId::of::<E>(), runner);
+    registry.samplers.insert(TypeId::of::<E>(), sampler);
+}
+
+/// Add the easing events for the marker `M` as messages.
+fn add_easing_messages<M: Send + Sync + 'static>(app: &mut App) {
+    app.add_message::<EasingCompleted<M>>()
+        .add_message::<EasingLoopCompleted<M>>()
+        .add_message::<EasingPingPongReversed<M>>()
+        .add_message::<EasingChainStepCompleted<M>>();
+}
+
+/// Components for which an easing system is running, and easings added for components that may not have one.
+#[derive(Resource, Default)]
+pub(crate) struct EasingsRegistry {
+    registered: HashSet<TypeId>,
+    warned: HashSet<TypeId>,
+    unchecked: Vec<(TypeId, &'static str)>,
+    runners: HashMap<TypeId, GroupRunner>,
+    samplers: HashMap<TypeId, TimelineSampler>,
+}
+
+impl EasingsRegistry {
+    /// How to run an easing of type `easing` in an [`EasingGroup`]
+    pub(crate) fn runner(&self, easing: TypeId) -> Option<GroupRunner> {
+        self.runners.get(&easing).copied()
+    }
+
+    /// How to sample an easing of type `easing` in a [`Timeline`]
+    pub(crate) fn sampler(&self, easing: TypeId) -> Option<TimelineSampler> {
+        self.samplers.get(&easing).copied()
+    }
+}
+
+/// Time elapsed since the last update, read from the `Time` selected with the [`EasingsPlugin`].
+#[derive(Resource, Default)]
+pub(crate) struct EasingsDelta(pub(crate) Duration);
+
+/// Resource scaling the time elapsed for all easings, on top of the speed of each easing.
+///
+/// Negative values are treated as 0, pausing all easings.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct EasingTimeScale(pub f32);
+
+impl Default for EasingTimeScale {
+    fn default() -> Self {
+        EasingTimeScale(1.)
+    }
+}
+
+impl EasingTimeScale {
+    fn scale(&self, delta: Duration) -> Duration {
+        delta.mul_f32(self.0.max(0.))
+    }
+}
+
+#[cfg(feature = "color")]
+type ColorMixParam<'w> = Res<'w, crate::ColorMix>;
+#[cfg(not(feature = "color"))]
+type ColorMixParam<'w> = ();
+
+/// Time elapsed and settings of the app, shared by all the easing systems
+#[derive(SystemParam)]
+struct EasingsUpdate<'w> {
+    delta: Res<'w, EasingsDelta>,
+    #[cfg_attr(not(feature = "color"), allow(dead_code))]
+    color_mix: ColorMixParam<'w>,
+}
+
+impl EasingsUpdate<'_> {
+    /// Time elapsed since the last update, scaled by the [`EasingTimeScale`]
+    fn delta(&self) -> Duration {
+        self.delta.0
+    }
+
+    /// Settings used to interpolate values
+    fn settings(&self) -> EaseSettings {
+        EaseSettings {
+            #[cfg(feature = "color")]
+            color_mix: *self.color_mix,
+        }
+    }
+}
+
+fn update_easings_delta<T: Default + Send + Sync + 'static>(
     time: Res<Time<T>>,
+    scale: Res<EasingTimeScale>,
+    mut delta: ResMut<EasingsDelta>,
+) {
+    delta.0 = scale.scale(time.delta());
+}
+
+/// Hook on easing insertion, remembering what is eased so that it can be checked once systems ran.
+pub(crate) fn check_registered<C: 'static>(mut world: DeferredWorld, _context: HookContext) {
+    if let Some(mut registry) = world.get_resource_mut::<EasingsRegistry>()
+        && !registry.registered.contains(&TypeId::of::<C>())
+        && !registry.warned.contains(&TypeId::of::<C>())
+    {
+        registry
+            .unchecked
+            .push((TypeId::of::<C>(), std::any::type_name::<C>()));
+    }
+}
+
+fn warn_unregistered_easings(mut registry: ResMut<EasingsRegistry>) {
+    if registry.unchecked.is_empty() {
+        return;
+    }
+    for (type_id, name) in std::mem::take(&mut registry.unchecked) {
+        if !registry.registered.contains(&type_id) && registry.warned.insert(type_id) {
+            warn!(
+                "An easing on `{name}` was added, but `{name}` has not been registered for easing. \
+                Register it with `app.register_easing::<{name}>()`, `app.register_custom_easing::<{name}>()`, \
+                `app.register_lens::<{name}>()` or `app.register_custom_lens::<{name}>()`."
+            );
+        }
+    }
+}
+
+fn ease_system<C, I>(
+    mut commands: Commands,
+    update: EasingsUpdate,
     entity_query: Query<Entity, With<C>>,
     mut object_query: Query<&mut C>,
     mut easing_query: Query<&mut EasingComponent<C>>,
     mut chain_query: Query<&mut EasingChainComponent<C>>,
 ) where
-    EaseValue<C>: interpolation::Lerp<Scalar = f32>,
+    C: Component<Mutability = Mutable> + Default,
+    I: Interpolate<C>,
+{
+    drive_easings::<C, I>(
+        &mut commands,
+        update.delta(),
+        &entity_query,
+        &mut object_query,
+        &mut easing_query,
+        &mut chain_query,
+        &update.settings(),
+    );
+}
+
+fn ease_tracks_system<C, I>(
+    mut commands: Commands,
+    update: EasingsUpdate,
+    mut query: Query<(Entity, &mut EasingTracks<C>, &mut C)>,
+) where
+    C: Component<Mutability = Mutable> + Default,
+    I: Interpolate<C>,
 {
-    for entity in entity_query.iter() {
-        if let Ok(ref mut easing) = easing_query.get_mut(entity) {
-            if easing.state == EasingState::Play {
-                easing.timer.tick(time.delta());
-            } else {
-                continue;
-            }
-            if easing.paused {
-                if easing.timer.just_finished() {
-                    match easing.easing_type {
-                        EasingType::Once { duration }
-                        | EasingType::Loop { duration, .. }
-                        | EasingType::PingPong { duration, .. } => {
-                            easing.timer.set_duration(duration);
-                        }
-                    }
-                    easing.timer.reset();
-                    easing.paused = false;
-                }
-            } else {
-                if easing.timer.duration().as_secs_f32() != 0. {
-                    let mut object = object_query.get_mut(entity).unwrap();
-                    let progress = if easing.direction == EasingDirection::Forward {
-                        easing.timer.fraction()
-                    } else {
-                        easing.timer.fraction_remaining()
-                    };
-                    let factor = progress.compute(easing.ease_function);
-                    if let Some(ref start) = easing.start {
-                        *object = interpolation::lerp(start, &easing.end, &factor).0;
-                    } else {
-                        *object =
-                            interpolation::lerp(&EaseValue(C::default()), &easing.end, &factor).0;
-                    }
-                }
-                if easing.timer.is_finished() {
-                    match easing.easing_type {
-                        EasingType::Once { .. } => {
-                            commands.entity(entity).remove::<EasingComponent<C>>();
-                        }
-                        EasingType::Loop { pause, .. } => {
-                            if let Some(pause) = pause {
-                                easing.timer.set_duration(pause);
-                                easing.paused = true;
-                            }
-                            easing.timer.reset();
-                        }
-                        EasingType::PingPong { pause, .. } => {
-                            if let Some(pause) = pause {
-                                easing.timer.set_duration(pause);
-                                easing.paused = true;
-                            }
-                            easing.timer.reset();
-                            easing.direction.reverse();
-                        }
-                    }
-                }
-            }
-        } else if let Ok(ref mut easing_chain) = chain_query.get_mut(entity) {
-            let next = easing_chain.0.pop();
-            if let Some(mut next) = next {
-                let mut object = object_query.get_mut(entity).unwrap();
-                if next.start.is_none() {
-                    next.start = Some(EaseValue(std::mem::take(&mut object)));
-                }
-                if let Some(ref start) = next.start {
-                    *object = interpolation::lerp(start, &next.end, &0.).0;
-                } else {
-                    *object = interpolation::lerp(&EaseValue(C::default()), &next.end, &0.).0;
-                }
-
-                commands.entity(entity).insert(next);
-            } else {
-                commands.entity(entity).remove::<EasingChainComponent<C>>();
-            }
+    drive_tracks::<C, I>(
+        &mut commands,
+        update.delta(),
+        &mut query,
+        &update.settings(),
+    );
+}
+
+fn ease_keyframes_system<C, I>(
+    mut commands: Commands,
+    update: EasingsUpdate,
+    mut query: Query<(Entity, &mut EasingKeyframes<C>, &mut C)>,
+) where
+    C: Component<Mutability = Mutable>,
+    I: Interpolate<C>,
+{
+    drive_keyframes::<C, I>(
+        &mut commands,
+        update.delta(),
+        &mut query,
+        &update.settings(),
+    );
+}
+
+fn ease_groups_system(
+    mut commands: Commands,
+    update: EasingsUpdate,
+    registry: Res<EasingsRegistry>,
+    mut query: Query<(Entity, &mut EasingGroup, EntityMutExcept<EasingGroup>)>,
+) {
+    let settings = update.settings();
+    for (entity, mut group, mut entity_mut) in &mut query {
+        if group.run(&registry, &mut entity_mut, update.delta(), &settings) {
+            commands.entity(entity).remove::<EasingGroup>();
+            send_easing_event(&mut commands, EasingCompleted::<EasingGroup>::new(entity));
+        }
+    }
+}
+
+fn ease_timelines_system(
+    mut commands: Commands,
+    update: EasingsUpdate,
+    registry: Res<EasingsRegistry>,
+    mut timelines: Query<(Entity, &mut Timeline)>,
+    mut entities: Query<EntityMutExcept<Timeline>>,
+) {
+    let settings = update.settings();
+    for (entity, mut timeline) in &mut timelines {
+        if timeline.run(&registry, &mut entities, update.delta(), &settings) {
+            send_easing_event(&mut commands, EasingCompleted::<Timeline>::new(entity));
         }
     }
 }
 
+fn ease_lens_system<L, I>(
+    mut commands: Commands,
+    update: EasingsUpdate,
+    mut query: Query<(Entity, &mut EasingLens<L>, &mut L::Component)>,
+) where
+    L: Lens,
+    L::Field: Clone + Default,
+    I: Interpolate<L::Field>,
+{
+    drive_lens_easings::<L, I>(
+        &mut commands,
+        update.delta(),
+        &mut query,
+        &update.settings(),
+    );
+}
+
 /// Ease system for custom component. Add this system to your application with your component as a type parameter.
+///
+/// Prefer [`EasingsAppExt::register_custom_easing`], which also adds the easing events as messages and uses the `Time`
+/// selected with the [`EasingsPlugin`].
+#[allow(clippy::too_many_arguments)]
 pub fn custom_ease_system<
     T: Default + Send + Sync + 'static,
     C: CustomComponentEase
@@ -173,81 +554,30 @@ pub fn custom_ease_system<
     mut object_query: Query<&mut C>,
     mut easing_query: Query<&mut EasingComponent<C>>,
     mut chain_query: Query<&mut EasingChainComponent<C>>,
+    scale: Option<Res<EasingTimeScale>>,
+    #[cfg(feature = "color")] color_mix: Option<Res<crate::ColorMix>>,
+    mut registered: Local<bool>,
 ) {
-    for entity in entity_query.iter() {
-        if let Ok(ref mut easing) = easing_query.get_mut(entity) {
-            if easing.state == EasingState::Play {
-                easing.timer.tick(time.delta());
-            }
-            if easing.paused {
-                if easing.timer.just_finished() {
-                    match easing.easing_type {
-                        EasingType::Once { duration }
-                        | EasingType::Loop { duration, .. }
-                        | EasingType::PingPong { duration, .. } => {
-                            easing.timer.set_duration(duration);
-                        }
-                    }
-                    easing.timer.reset();
-                    easing.paused = false;
-                }
-            } else {
-                if easing.timer.duration().as_secs_f32() != 0. {
-                    let mut object = object_query.get_mut(entity).unwrap();
-
-                    let progress = if easing.direction == EasingDirection::Forward {
-                        easing.timer.fraction()
-                    } else {
-                        easing.timer.fraction_remaining()
-                    };
-                    let factor = progress.compute(easing.ease_function);
-                    if let Some(ref start) = easing.start {
-                        *object = interpolation::lerp(&start.0, &easing.end.0, &factor);
-                    } else {
-                        *object = interpolation::lerp(&C::default(), &easing.end.0, &factor);
-                    }
-                }
-                if easing.timer.is_finished() {
-                    match easing.easing_type {
-                        EasingType::Once { .. } => {
-                            commands.entity(entity).remove::<EasingComponent<C>>();
-                        }
-                        EasingType::Loop { pause, .. } => {
-                            if let Some(pause) = pause {
-                                easing.timer.set_duration(pause);
-                                easing.paused = true;
-                            }
-                            easing.timer.reset();
-                        }
-                        EasingType::PingPong { pause, .. } => {
-                            if let Some(pause) = pause {
-                                easing.timer.set_duration(pause);
-                                easing.paused = true;
-                            }
-                            easing.timer.reset();
-                            easing.direction.reverse();
-                        }
-                    }
-                }
-            }
-        } else if let Ok(ref mut easing_chain) = chain_query.get_mut(entity) {
-            let next = easing_chain.0.pop();
-            if let Some(mut next) = next {
-                let mut object = object_query.get_mut(entity).unwrap();
-
-                if next.start.is_none() {
-                    next.start = Some(EaseValue(std::mem::take(&mut object)));
-                }
-                if let Some(ref start) = next.start {
-                    *object = interpolation::lerp(&start.0, &next.end.0, &0.);
-                } else {
-                    *object = interpolation::lerp(&C::default(), &next.end.0, &0.);
-                }
-
-                commands.entity(entity).insert(next);
-            } else {
-                commands.entity(entity).remove::<EasingChainComponent<C>>();
-            }
-        }
+    if !*registered {
+        *registered = true;
+        commands.queue(|world: &mut World| {
+            world
+                .get_resource_or_init::<EasingsRegistry>()
+                .registered
+                .insert(TypeId::of::<C>());
+        });
     }
+    let settings = EaseSettings {
+        #[cfg(feature = "color")]
+        color_mix: color_mix.as_deref().copied().unwrap_or_default(),
+    };
+    drive_easings::<C, ComponentLerp>(
+        &mut commands,
+        scale.map_or(time.delta(), |scale| scale.scale(time.delta())),
+        &entity_query,
+        &mut object_query,
+        &mut easing_query,
+        &mut chain_query,
+        &settings,
+    );
 }
@@ -0,0 +1,90 @@
+use std::{f32::consts::TAU, sync::Arc};
+
+use bevy_math::{EulerRot, Quat, Vec3, Vec4};
+use bevy_transform::components::Transform;
+
+use crate::EasingComponent;
+
+/// How the rotation of a [`Transform`] or a [`Quat`] is eased, set with
+/// [`EasingComponent::with_rotation`](crate::EasingComponent::with_rotation)
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum RotationMode {
+    /// Normalized linear interpolation on the shortest path. This is the default, it is fast but the rotation speeds
+    /// up toward the middle of the easing
+    #[default]
+    Nlerp,
+    /// Spherical linear interpolation between the quaternions as they are, at constant speed. It can take the long
+    /// way around when the quaternions are in opposite hemispheres
+    Slerp,
+    /// Spherical linear interpolation on the shortest path, at constant speed
+    ShortestPath,
+    /// Ease each Euler angle from the start to the end, adding full turns around each axis
+    Euler {
+        /// Order of the Euler angles
+        order: EulerRot,
+        /// Number of full turns added around each axis, in the order of the Euler angles. A negative number turns
+        /// the other way
+        turns: Vec3,
+    },
+}
+
+impl RotationMode {
+    /// Spin `turns` full turns around the Z axis, on top of the rotation from the start to the end. A negative
+    /// number of turns spins clockwise
+    pub fn spin_z(turns: f32) -> Self {
+        RotationMode::Euler {
+            order: EulerRot::XYZ,
+            turns: Vec3::new(0., 0., turns),
+        }
+    }
+
+    /// Rotation between `start` and `end` at `factor`
+    pub fn interpolate(&self, start: Quat, end: Quat, factor: f32) -> Quat {
+        match *self {
+            RotationMode::Nlerp => start.lerp(end, factor),
+            RotationMode::Slerp => {
+                let dot = start.dot(end).clamp(-1., 1.);
+                let theta = dot.acos();
+                if theta.sin().abs() < 1e-4 {
+                    // the quaternions are too close or opposite, they are the same rotation
+                    Vec4::from(start)
+                        .lerp(Vec4::from(end), factor)
+                        .try_normalize()
+                        .map_or(start, Quat::from_vec4)
+                } else {
+                    (start * ((1. - factor) * theta).sin() + end * (factor * theta).sin())
+                        * (1. / theta.sin())
+                }
+            }
+            RotationMode::ShortestPath => start.slerp(end, factor),
+            RotationMode::Euler { order, turns } => {
+                let start = Vec3::from(start.to_euler(order));
+                let end = Vec3::from(end.to_euler(order));
+                let angles = start + (end - start + turns * TAU) * factor;
+                Quat::from_euler(order, angles.x, angles.y, angles.z)
+            }
+        }
+    }
+}
+
+impl EasingComponent<Transform> {
+    /// Ease the rotation with a [`RotationMode`], instead of the default [`RotationMode::Nlerp`]
+    pub fn with_rotation(mut self, mode: RotationMode) -> Self {
+        self.interpolation = Some(Arc::new(move |start, end, factor, _| Transform {
+            translation: start.translation.lerp(end.translation, factor),
+            scale: start.scale.lerp(end.scale, factor),
+            rotation: mode.interpolate(start.rotation, end.rotation, factor),
+        }));
+        self
+    }
+}
+
+impl EasingComponent<Quat> {
+    /// Ease the rotation with a [`RotationMode`], instead of the default [`RotationMode::Nlerp`]
+    pub fn with_rotation(mut self, mode: RotationMode) -> Self {
+        self.interpolation = Some(Arc::new(move |start, end, factor, _| {
+            mode.interpolate(*start, *end, factor)
+        }));
+        self
+    }
+}
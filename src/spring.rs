@@ -0,0 +1,148 @@
+use std::time::Duration;
+
+/// Distance to the target, relative to the eased distance, under which a spring is settled
+const SETTLED: f32 = 0.001;
+
+/// A damped harmonic oscillator, moving from 0 to 1.
+///
+/// The position is the easing factor, and the velocity is in factor per second.
+#[derive(Clone, Copy)]
+pub(crate) struct Spring {
+    /// Undamped angular frequency
+    omega: f32,
+    /// Damping ratio, 1 for a critically damped spring
+    zeta: f32,
+}
+
+impl Spring {
+    pub(crate) fn new(stiffness: f32, damping: f32, mass: f32) -> Self {
+        Self {
+            omega: (stiffness / mass).sqrt(),
+            zeta: damping / (2. * (stiffness * mass).sqrt()),
+        }
+    }
+
+    /// A critically damped spring reaching its target in about `smooth_time`.
+    pub(crate) fn smooth_damp(smooth_time: Duration) -> Self {
+        Self {
+            omega: 2. / smooth_time.as_secs_f32(),
+            zeta: 1.,
+        }
+    }
+
+    /// Position and velocity of the spring `time` seconds after starting at 0 with `velocity`.
+    pub(crate) fn state(&self, velocity: f32, time: f32) -> (f32, f32) {
+        let (displacement, velocity) = match self.solution(velocity) {
+            Solution::Underdamped { decay, omega, a, b } => {
+                let (sin, cos) = (omega * time).sin_cos();
+                let envelope = (-decay * time).exp();
+                (
+                    envelope * (a * cos + b * sin),
+                    envelope * ((b * omega - decay * a) * cos - (a * omega + decay * b) * sin),
+                )
+            }
+            Solution::Critical { omega, a, b } => {
+                let envelope = (-omega * time).exp();
+                (
+                    envelope * (a + b * time),
+                    envelope * (b - omega * (a + b * time)),
+                )
+            }
+            Solution::Overdamped { r1, r2, c1, c2 } => {
+                let (e1, e2) = ((r1 * time).exp(), (r2 * time).exp());
+                (c1 * e1 + c2 * e2, r1 * c1 * e1 + r2 * c2 * e2)
+            }
+        };
+        (1. + displacement, velocity)
+    }
+
+    /// Time the spring takes to settle on its target after starting at 0 with `velocity`.
+    pub(crate) fn settle_duration(&self, velocity: f32) -> Duration {
+        let solution = self.solution(velocity);
+        let bound = |time: f32| match solution {
+            Solution::Underdamped { decay, a, b, .. } => {
+                (a * a + b * b).sqrt() * (-decay * time).exp()
+            }
+            Solution::Critical { omega, a, b } => {
+                (a.abs() + b.abs() * time) * (-omega * time).exp()
+            }
+            Solution::Overdamped { r1, r2, c1, c2 } => {
+                c1.abs() * (r1 * time).exp() + c2.abs() * (r2 * time).exp()
+            }
+        };
+        if !self.omega.is_finite() || self.omega <= 0. || self.zeta <= 0. {
+            return Duration::ZERO;
+        }
+
+        let mut high = 0.1;
+        for _ in 0..32 {
+            if bound(high) < SETTLED {
+                break;
+            }
+            high *= 2.;
+        }
+        let mut low = 0.;
+        for _ in 0..32 {
+            let middle = (low + high) / 2.;
+            if bound(middle) < SETTLED {
+                high = middle;
+            } else {
+                low = middle;
+            }
+        }
+        Duration::from_secs_f32(high)
+    }
+
+    /// Coefficients of the displacement to the target, starting at -1 with `velocity`
+    fn solution(&self, velocity: f32) -> Solution {
+        let start = -1.;
+        if (self.zeta - 1.).abs() < 1e-4 {
+            Solution::Critical {
+                omega: self.omega,
+                a: start,
+                b: velocity + self.omega * start,
+            }
+        } else if self.zeta < 1. {
+            let decay = self.zeta * self.omega;
+            let omega = self.omega * (1. - self.zeta * self.zeta).sqrt();
+            Solution::Underdamped {
+                decay,
+                omega,
+                a: start,
+                b: (velocity + decay * start) / omega,
+            }
+        } else {
+            let spread = self.omega * (self.zeta * self.zeta - 1.).sqrt();
+            let r1 = -self.zeta * self.omega + spread;
+            let r2 = -self.zeta * self.omega - spread;
+            let c2 = (velocity - r1 * start) / (r2 - r1);
+            Solution::Overdamped {
+                r1,
+                r2,
+                c1: start - c2,
+                c2,
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Solution {
+    Underdamped {
+        decay: f32,
+        omega: f32,
+        a: f32,
+        b: f32,
+    },
+    Critical {
+        omega: f32,
+        a: f32,
+        b: f32,
+    },
+    Overdamped {
+        r1: f32,
+        r2: f32,
+        c1: f32,
+        c2: f32,
+    },
+}
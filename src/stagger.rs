@@ -0,0 +1,168 @@
+use std::time::Duration;
+
+use crate::EaseMethod;
+
+/// Order in which the items of a [`StaggerDelay`] start
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StaggerOrder {
+    /// Items start in order, the first one first
+    Linear,
+    /// Items start in reverse order, the last one first
+    Reverse,
+    /// Items start from the center toward both ends
+    FromCenter,
+    /// Items start from both ends toward the center
+    FromEdges,
+    /// Items start in a random order, always the same for a seed
+    Random {
+        /// Seed of the random order
+        seed: u64,
+    },
+    /// Items are laid out in a grid, row after row, and start from a cell outward
+    Grid {
+        /// Number of columns of the grid
+        columns: usize,
+        /// Column and row of the cell starting first
+        origin: (usize, usize),
+    },
+}
+
+/// Distribute delays over a set of items, to use with [`EasingComponent::delay`](crate::EasingComponent::delay).
+///
+/// Each item is delayed by `each` for each step of distance from the first item to start, following a
+/// [`StaggerOrder`]. The delays can be distributed following an [`EaseMethod`], so that items start closer together
+/// at the beginning or at the end.
+///
+/// This is different from [`Stagger`](crate::Stagger), which staggers easings on the same entity.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_easings::{Ease, EaseFunction, EasingType, StaggerDelay, StaggerOrder};
+/// use std::time::Duration;
+///
+/// fn system(mut commands: Commands) {
+///     let stagger = StaggerDelay::new(Duration::from_millis(50))
+///         .order(StaggerOrder::FromCenter)
+///         .ease(EaseFunction::QuadraticIn);
+///     for (i, delay) in stagger.delays(10).into_iter().enumerate() {
+///         let transform = Transform::from_xyz(i as f32 * 20., 0., 0.);
+///         commands.spawn((
+///             transform,
+///             transform
+///                 .ease_to(
+///                     transform.with_translation(Vec3::new(i as f32 * 20., 100., 0.)),
+///                     EaseFunction::BackOut,
+///                     EasingType::Once { duration: Duration::from_millis(500) },
+///                 )
+///                 .delay(delay),
+///         ));
+///     }
+/// }
+/// ```
+#[derive(Clone)]
+pub struct StaggerDelay {
+    each: Duration,
+    order: StaggerOrder,
+    ease_method: Option<EaseMethod>,
+}
+
+impl StaggerDelay {
+    /// Delay items by `each` for each step of distance, in [`StaggerOrder::Linear`] order
+    pub fn new(each: Duration) -> Self {
+        Self {
+            each,
+            order: StaggerOrder::Linear,
+            ease_method: None,
+        }
+    }
+
+    /// Set the order in which items start
+    pub fn order(mut self, order: StaggerOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Distribute the delays following `ease_method` instead of evenly
+    pub fn ease(mut self, ease_method: impl Into<EaseMethod>) -> Self {
+        self.ease_method = Some(ease_method.into());
+        self
+    }
+
+    /// Delay of the item `index` out of `count` items
+    pub fn delay(&self, index: usize, count: usize) -> Duration {
+        self.delays(count)
+            .get(index)
+            .copied()
+            .unwrap_or(Duration::ZERO)
+    }
+
+    /// Delays of `count` items
+    pub fn delays(&self, count: usize) -> Vec<Duration> {
+        let distances = self.distances(count);
+        let max = distances.iter().copied().fold(0., f32::max);
+        distances
+            .into_iter()
+            .map(|distance| {
+                if max == 0. {
+                    return Duration::ZERO;
+                }
+                let factor = self
+                    .ease_method
+                    .as_ref()
+                    .map_or(distance / max, |ease_method| {
+                        ease_method.factor(distance / max)
+                    });
+                self.each.mul_f64(f64::from((max * factor).max(0.)))
+            })
+            .collect()
+    }
+
+    /// Distance of each item to the first one to start, in steps
+    fn distances(&self, count: usize) -> Vec<f32> {
+        let last = count.saturating_sub(1) as f32;
+        match self.order {
+            StaggerOrder::Linear => (0..count).map(|i| i as f32).collect(),
+            StaggerOrder::Reverse => (0..count).map(|i| last - i as f32).collect(),
+            StaggerOrder::FromCenter => (0..count).map(|i| (i as f32 - last / 2.).abs()).collect(),
+            StaggerOrder::FromEdges => (0..count)
+                .map(|i| (i as f32).min(last - i as f32))
+                .collect(),
+            StaggerOrder::Random { seed } => {
+                // Fisher-Yates shuffle of the ranks, with a splitmix64 generator
+                let mut state = seed;
+                let mut next = move || {
+                    state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+                    let mut z = state;
+                    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+                    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+                    z ^ (z >> 31)
+                };
+                let mut ranks: Vec<f32> = (0..count).map(|i| i as f32).collect();
+                for i in (1..count).rev() {
+                    ranks.swap(i, (next() % (i as u64 + 1)) as usize);
+                }
+                ranks
+            }
+            StaggerOrder::Grid { columns, origin } => {
+                let columns = columns.max(1);
+                (0..count)
+                    .map(|i| {
+                        let x = (i % columns) as f32 - origin.0 as f32;
+                        let y = (i / columns) as f32 - origin.1 as f32;
+                        (x * x + y * y).sqrt()
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for StaggerDelay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StaggerDelay")
+            .field("each", &self.each)
+            .field("order", &self.order)
+            .finish()
+    }
+}
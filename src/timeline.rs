@@ -0,0 +1,466 @@
+use std::{
+    any::{Any, TypeId},
+    time::Duration,
+};
+
+use bevy_ecs::{
+    change_detection::DetectChangesMut,
+    component::{Component, Mutable},
+    entity::Entity,
+    name::Name,
+    system::Query,
+    world::EntityMutExcept,
+};
+
+use crate::{
+    EaseValue, EasingComponent, EasingDirection, EasingLens, EasingState, Lens,
+    driver::{EaseSettings, Interpolate},
+    plugin::EasingsRegistry,
+};
+
+/// Set a type-erased easing on an entity to its value `time` after it started
+pub(crate) type TimelineSampler =
+    fn(&mut (dyn Any + Send + Sync), &mut EntityMutExcept<Timeline>, Duration, &EaseSettings);
+
+/// The entities targeted by a track of a [`Timeline`].
+///
+/// Targets are resolved the first time the track changes them.
+#[derive(Debug, Clone)]
+pub struct TimelineTarget(Target);
+
+#[derive(Debug, Clone)]
+enum Target {
+    Entity(Entity),
+    Name(Name),
+    Marker(TypeId),
+}
+
+impl TimelineTarget {
+    /// Target a single entity
+    pub fn entity(entity: Entity) -> Self {
+        TimelineTarget(Target::Entity(entity))
+    }
+
+    /// Target all entities with this [`Name`]
+    pub fn name(name: impl Into<Name>) -> Self {
+        TimelineTarget(Target::Name(name.into()))
+    }
+
+    /// Target all entities with the component `M`
+    pub fn marker<M: Component>() -> Self {
+        TimelineTarget(Target::Marker(TypeId::of::<M>()))
+    }
+}
+
+impl From<Entity> for TimelineTarget {
+    fn from(entity: Entity) -> Self {
+        Self::entity(entity)
+    }
+}
+
+impl From<Name> for TimelineTarget {
+    fn from(name: Name) -> Self {
+        Self::name(name)
+    }
+}
+
+/// An easing played by a track of a [`Timeline`].
+///
+/// It can be created from an [`EasingComponent`] or an [`EasingLens`] of a registered component. A delay set on the
+/// easing is added to the offset of its track.
+pub struct TimelineEasing {
+    easing: Box<dyn Any + Send + Sync>,
+    clone: fn(&(dyn Any + Send + Sync)) -> Box<dyn Any + Send + Sync>,
+    name: &'static str,
+    delay: Duration,
+    duration: Duration,
+}
+
+fn clone_easing<E: Clone + Send + Sync + 'static>(
+    easing: &(dyn Any + Send + Sync),
+) -> Box<dyn Any + Send + Sync> {
+    Box::new(
+        easing
+            .downcast_ref::<E>()
+            .expect("easing of the expected type")
+            .clone(),
+    )
+}
+
+impl<C: Component + Clone> From<EasingComponent<C>> for TimelineEasing {
+    fn from(mut easing: EasingComponent<C>) -> Self {
+        let delay = easing.take_delay();
+        TimelineEasing {
+            duration: easing.duration(),
+            easing: Box::new(easing),
+            clone: clone_easing::<EasingComponent<C>>,
+            name: std::any::type_name::<C>(),
+            delay,
+        }
+    }
+}
+
+impl<L: Lens> From<EasingLens<L>> for TimelineEasing
+where
+    L::Field: Clone,
+{
+    fn from(mut easing: EasingLens<L>) -> Self {
+        let delay = easing.take_delay();
+        TimelineEasing {
+            duration: easing.duration(),
+            easing: Box::new(easing),
+            clone: clone_easing::<EasingLens<L>>,
+            name: std::any::type_name::<L>(),
+            delay,
+        }
+    }
+}
+
+struct Track {
+    target: TimelineTarget,
+    offset: Duration,
+    easing: TimelineEasing,
+    /// An instance of the easing for each targeted entity, once resolved
+    instances: Option<Vec<(Entity, Box<dyn Any + Send + Sync>)>>,
+}
+
+/// Component to play easings on several entities as one unit, each one starting at an offset from the start of the
+/// timeline.
+///
+/// Easings are played once, whatever their [`EasingType`](crate::EasingType). A timeline can be paused, played
+/// backward, moved to any time, or played between two labels. Once it reached its end, its start when played backward,
+/// or the end of the labelled part played, it is paused and stays on its entity so that it can be played again.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_easings::{Ease, EaseFunction, EasingType, Timeline, TimelineTarget};
+/// use std::time::Duration;
+///
+/// #[derive(Component)]
+/// struct MenuButton;
+///
+/// fn system(mut commands: Commands) {
+///     let once = EasingType::Once { duration: Duration::from_millis(500) };
+///     let title = commands.spawn(Transform::default()).id();
+///     commands.spawn(
+///         Timeline::new()
+///             .label("intro", Duration::ZERO)
+///             .add(
+///                 title,
+///                 Duration::ZERO,
+///                 Transform::default().ease_to(
+///                     Transform::from_xyz(0., 100., 0.),
+///                     EaseFunction::QuadraticOut,
+///                     once,
+///                 ),
+///             )
+///             .add(
+///                 TimelineTarget::marker::<MenuButton>(),
+///                 Duration::from_millis(250),
+///                 Transform::from_scale(Vec3::ZERO).ease_to(
+///                     Transform::default(),
+///                     EaseFunction::BackOut,
+///                     once,
+///                 ),
+///             )
+///             .label("outro", Duration::from_secs(2)),
+///     );
+/// }
+/// ```
+#[derive(Component)]
+pub struct Timeline {
+    tracks: Vec<Track>,
+    labels: Vec<(String, Duration)>,
+    elapsed: Duration,
+    /// Time at which the tracks were last sampled
+    sampled: Duration,
+    /// Time at which to pause when playing from a label
+    until: Option<Duration>,
+    direction: EasingDirection,
+    seeked: bool,
+    /// Control if this timeline is played or not
+    pub state: EasingState,
+}
+
+impl Timeline {
+    /// Create an empty timeline
+    pub fn new() -> Self {
+        Self {
+            tracks: Vec::new(),
+            labels: Vec::new(),
+            elapsed: Duration::ZERO,
+            sampled: Duration::ZERO,
+            until: None,
+            direction: EasingDirection::Forward,
+            seeked: false,
+            state: EasingState::Play,
+        }
+    }
+
+    /// Add a track playing `easing` on `target`, starting `offset` after the start of the timeline
+    pub fn add(
+        mut self,
+        target: impl Into<TimelineTarget>,
+        offset: Duration,
+        easing: impl Into<TimelineEasing>,
+    ) -> Self {
+        let easing = easing.into();
+        self.tracks.push(Track {
+            target: target.into(),
+            offset: offset + easing.delay,
+            easing,
+            instances: None,
+        });
+        self
+    }
+
+    /// Name the time `time` of the timeline, to move to it with [`Timeline::seek_label`] or to play from it with
+    /// [`Timeline::play_label`]
+    pub fn label(mut self, name: impl Into<String>, time: Duration) -> Self {
+        let name = name.into();
+        self.labels.retain(|(label, _)| *label != name);
+        self.labels.push((name, time));
+        self
+    }
+
+    /// Time of the label `name`
+    pub fn label_time(&self, name: &str) -> Option<Duration> {
+        self.labels
+            .iter()
+            .find(|(label, _)| label == name)
+            .map(|(_, time)| *time)
+    }
+
+    /// Duration of the timeline, which is the end of its last track
+    pub fn duration(&self) -> Duration {
+        self.tracks
+            .iter()
+            .map(|track| track.offset + track.easing.duration)
+            .max()
+            .unwrap_or(Duration::ZERO)
+    }
+
+    /// Current time in the timeline
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Direction in which the timeline is played
+    pub fn direction(&self) -> EasingDirection {
+        self.direction
+    }
+
+    /// Play the timeline forward
+    pub fn play(&mut self) {
+        self.until = None;
+        self.direction = EasingDirection::Forward;
+        self.state = EasingState::Play;
+    }
+
+    /// Pause the timeline
+    pub fn pause(&mut self) {
+        self.state = EasingState::Paused;
+    }
+
+    /// Play the timeline backward, toward its start
+    pub fn reverse(&mut self) {
+        self.until = None;
+        self.direction = EasingDirection::Backward;
+        self.state = EasingState::Play;
+    }
+
+    /// Move to `time` in the timeline. Entities will be updated the next time easings run, even if paused.
+    pub fn seek(&mut self, time: Duration) {
+        self.elapsed = time.min(self.duration());
+        self.seeked = true;
+    }
+
+    /// Move to the label `name`, returning `false` if there is no such label
+    pub fn seek_label(&mut self, name: &str) -> bool {
+        let Some(time) = self.label_time(name) else {
+            return false;
+        };
+        self.seek(time);
+        true
+    }
+
+    /// Play the timeline forward from the label `name` until the next label, returning `false` if there is no such
+    /// label
+    pub fn play_label(&mut self, name: &str) -> bool {
+        if !self.seek_label(name) {
+            return false;
+        }
+        self.play();
+        self.until = self
+            .labels
+            .iter()
+            .map(|(_, time)| *time)
+            .filter(|time| *time > self.elapsed)
+            .min();
+        true
+    }
+
+    /// Advance the timeline by `delta` and update the targeted entities, returning `true` once it stopped
+    pub(crate) fn run(
+        &mut self,
+        registry: &EasingsRegistry,
+        entities: &mut Query<EntityMutExcept<Timeline>>,
+        delta: Duration,
+        settings: &EaseSettings,
+    ) -> bool {
+        let seeked = std::mem::take(&mut self.seeked);
+        let mut finished = false;
+        if self.state == EasingState::Play {
+            let end = self
+                .until
+                .map_or(self.duration(), |until| until.min(self.duration()));
+            self.elapsed = match self.direction {
+                EasingDirection::Forward => (self.elapsed + delta).min(end),
+                EasingDirection::Backward => self.elapsed.saturating_sub(delta),
+            };
+            finished = match self.direction {
+                EasingDirection::Forward => self.elapsed == end,
+                EasingDirection::Backward => self.elapsed.is_zero(),
+            };
+            if finished {
+                self.until = None;
+                self.state = EasingState::Paused;
+            }
+        } else if !seeked {
+            return false;
+        }
+
+        let (previous, elapsed) = (self.sampled, self.elapsed);
+        self.sampled = elapsed;
+        for track in &mut self.tracks {
+            let local =
+                |time: Duration| time.saturating_sub(track.offset).min(track.easing.duration);
+            // tracks are only updated while their time changes, leaving entities free before and after them
+            let time = local(elapsed);
+            if time == local(previous) {
+                continue;
+            }
+            let Some(sampler) = registry.sampler((*track.easing.easing).type_id()) else {
+                // the track is marked as resolved without entities, so that the warning is logged once
+                if track.instances.replace(Vec::new()).is_none() {
+                    bevy_log::warn!(
+                        "An easing on `{name}` was added to a `Timeline`, but `{name}` has not been registered \
+                        for easing. It is skipped.",
+                        name = track.easing.name
+                    );
+                }
+                continue;
+            };
+            let instances = track.instances.get_or_insert_with(|| {
+                resolve(&track.target, entities)
+                    .into_iter()
+                    .map(|entity| (entity, (track.easing.clone)(track.easing.easing.as_ref())))
+                    .collect()
+            });
+            for (entity, easing) in instances {
+                if let Ok(mut entity) = entities.get_mut(*entity) {
+                    sampler(easing.as_mut(), &mut entity, time, settings);
+                }
+            }
+        }
+        finished
+    }
+}
+
+/// Entities currently matching `target`
+fn resolve(target: &TimelineTarget, entities: &Query<EntityMutExcept<Timeline>>) -> Vec<Entity> {
+    match &target.0 {
+        Target::Entity(entity) => vec![*entity],
+        Target::Name(name) => entities
+            .iter()
+            .filter(|entity| entity.get::<Name>() == Some(name))
+            .map(|entity| entity.id())
+            .collect(),
+        Target::Marker(marker) => entities
+            .iter()
+            .filter(|entity| entity.contains_type_id(*marker))
+            .map(|entity| entity.id())
+            .collect(),
+    }
+}
+
+impl Default for Timeline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for Timeline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Timeline")
+            .field("tracks", &self.tracks)
+            .field("labels", &self.labels)
+            .field("elapsed", &self.elapsed)
+            .field("direction", &self.direction)
+            .field("state", &self.state)
+            .finish()
+    }
+}
+
+impl std::fmt::Debug for Track {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Track")
+            .field("target", &self.target)
+            .field("offset", &self.offset)
+            .field("easing", &self.easing)
+            .finish()
+    }
+}
+
+impl std::fmt::Debug for TimelineEasing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("TimelineEasing").field(&self.name).finish()
+    }
+}
+
+/// Sample an [`EasingComponent`] of a timeline
+pub(crate) fn sample_component<C, I>(
+    easing: &mut (dyn Any + Send + Sync),
+    entity: &mut EntityMutExcept<Timeline>,
+    time: Duration,
+    settings: &EaseSettings,
+) where
+    C: Component<Mutability = Mutable> + Default,
+    I: Interpolate<C>,
+{
+    let Some(easing) = easing.downcast_mut::<EasingComponent<C>>() else {
+        return;
+    };
+    let Some(mut object) = entity.get_mut::<C>() else {
+        return;
+    };
+    let eased = easing.sample::<I>(time, settings);
+    *object = easing.blend_into::<I>(std::mem::take(&mut object), eased, settings);
+}
+
+/// Sample an [`EasingLens`] of a timeline
+pub(crate) fn sample_lens<L, I>(
+    easing: &mut (dyn Any + Send + Sync),
+    entity: &mut EntityMutExcept<Timeline>,
+    time: Duration,
+    settings: &EaseSettings,
+) where
+    L: Lens,
+    L::Field: Clone + Default,
+    I: Interpolate<L::Field>,
+{
+    let Some(lens) = easing.downcast_mut::<EasingLens<L>>() else {
+        return;
+    };
+    let Some(mut component) = entity.get_mut::<L::Component>() else {
+        return;
+    };
+    if lens.start.is_none() {
+        let current = L::field(component.bypass_change_detection()).clone();
+        lens.start = Some(EaseValue(current));
+    }
+    let eased = lens.sample::<I>(time, settings);
+    let field = L::field(&mut component);
+    *field = lens.blend_into::<I>(std::mem::take(field), eased, settings);
+}
@@ -0,0 +1,194 @@
+use std::str::FromStr;
+
+use crate::EaseMethod;
+
+/// When the jumps of an [`EaseMethod::Steps`] happen, following the CSS `steps()` timing function
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StepPosition {
+    /// The first jump happens when the easing starts
+    JumpStart,
+    /// The last jump happens when the easing ends
+    #[default]
+    JumpEnd,
+    /// Jumps happen both when the easing starts and when it ends
+    JumpBoth,
+    /// No jump happens when the easing starts or ends
+    JumpNone,
+}
+
+/// Value of a CSS `cubic-bezier(x1, y1, x2, y2)` timing function at `progress`
+pub(crate) fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32, progress: f32) -> f32 {
+    let bezier = |a: f32, b: f32, t: f32| {
+        let u = 1. - t;
+        3. * u * u * t * a + 3. * u * t * t * b + t * t * t
+    };
+    let derivative = |a: f32, b: f32, t: f32| {
+        let u = 1. - t;
+        3. * u * u * a + 6. * u * t * (b - a) + 3. * t * t * (1. - b)
+    };
+    if progress <= 0. {
+        return 0.;
+    }
+    if progress >= 1. {
+        return 1.;
+    }
+    // with both control points on the diagonal, the curve is the identity
+    if x1 == y1 && x2 == y2 {
+        return progress;
+    }
+
+    // find the curve parameter for this progress, first with Newton's method then by bisection
+    let mut t = progress;
+    for _ in 0..8 {
+        let error = bezier(x1, x2, t) - progress;
+        if error.abs() < 1e-6 {
+            return bezier(y1, y2, t);
+        }
+        let slope = derivative(x1, x2, t);
+        if slope.abs() < 1e-6 {
+            break;
+        }
+        t -= error / slope;
+    }
+    let (mut low, mut high) = (0., 1.);
+    t = progress;
+    for _ in 0..32 {
+        let value = bezier(x1, x2, t);
+        if (value - progress).abs() < 1e-6 {
+            break;
+        }
+        if value < progress {
+            low = t;
+        } else {
+            high = t;
+        }
+        t = (low + high) / 2.;
+    }
+    bezier(y1, y2, t)
+}
+
+/// Value of a CSS `steps(steps, position)` timing function at `progress`
+pub(crate) fn steps(steps: u32, position: StepPosition, progress: f32) -> f32 {
+    let steps = steps.max(1);
+    let jumps = match position {
+        StepPosition::JumpStart | StepPosition::JumpEnd => steps,
+        StepPosition::JumpBoth => steps + 1,
+        StepPosition::JumpNone => steps.max(2) - 1,
+    };
+    let mut step = (progress * steps as f32).floor();
+    if matches!(position, StepPosition::JumpStart | StepPosition::JumpBoth) {
+        step += 1.;
+    }
+    step.clamp(0., jumps as f32) / jumps as f32
+}
+
+/// Error returned when parsing an [`EaseMethod`] from a CSS timing function fails
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseEaseMethodError {
+    input: String,
+    reason: &'static str,
+}
+
+impl ParseEaseMethodError {
+    fn new(input: &str, reason: &'static str) -> Self {
+        Self {
+            input: input.to_string(),
+            reason,
+        }
+    }
+}
+
+impl std::fmt::Display for ParseEaseMethodError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid timing function `{}`: {}",
+            self.input, self.reason
+        )
+    }
+}
+
+impl std::error::Error for ParseEaseMethodError {}
+
+impl FromStr for EaseMethod {
+    type Err = ParseEaseMethodError;
+
+    /// Parse a CSS timing function, like `ease-in-out`, `cubic-bezier(0.1, 0.7, 1.0, 0.1)` or `steps(4, jump-end)`
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let error = |reason| ParseEaseMethodError::new(input, reason);
+        let bezier = |x1, y1, x2, y2| EaseMethod::CubicBezier { x1, y1, x2, y2 };
+
+        let trimmed = input.trim();
+        match trimmed {
+            // `EaseMethod::Linear` snaps close to the ends, CSS `linear` is the identity
+            "linear" => return Ok(bezier(0., 0., 1., 1.)),
+            "ease" => return Ok(bezier(0.25, 0.1, 0.25, 1.)),
+            "ease-in" => return Ok(bezier(0.42, 0., 1., 1.)),
+            "ease-out" => return Ok(bezier(0., 0., 0.58, 1.)),
+            "ease-in-out" => return Ok(bezier(0.42, 0., 0.58, 1.)),
+            "step-start" => {
+                return Ok(EaseMethod::Steps {
+                    steps: 1,
+                    position: StepPosition::JumpStart,
+                });
+            }
+            "step-end" => {
+                return Ok(EaseMethod::Steps {
+                    steps: 1,
+                    position: StepPosition::JumpEnd,
+                });
+            }
+            _ => (),
+        }
+
+        let (name, arguments) = trimmed
+            .strip_suffix(')')
+            .and_then(|function| function.split_once('('))
+            .ok_or_else(|| error("unknown timing function"))?;
+        let arguments: Vec<&str> = arguments.split(',').map(str::trim).collect();
+        match name.trim() {
+            "cubic-bezier" => {
+                let [x1, y1, x2, y2] = arguments[..] else {
+                    return Err(error("`cubic-bezier` expects four numbers"));
+                };
+                let parse = |value: &str| {
+                    value
+                        .parse::<f32>()
+                        .map_err(|_| error("`cubic-bezier` expects four numbers"))
+                };
+                let (x1, y1, x2, y2) = (parse(x1)?, parse(y1)?, parse(x2)?, parse(y2)?);
+                if !(0. ..=1.).contains(&x1) || !(0. ..=1.).contains(&x2) {
+                    return Err(error("x coordinates must be between 0 and 1"));
+                }
+                Ok(bezier(x1, y1, x2, y2))
+            }
+            "steps" => {
+                let (steps, position) = match arguments[..] {
+                    [steps] => (steps, StepPosition::JumpEnd),
+                    [steps, position] => (
+                        steps,
+                        match position {
+                            "jump-start" | "start" => StepPosition::JumpStart,
+                            "jump-end" | "end" => StepPosition::JumpEnd,
+                            "jump-both" => StepPosition::JumpBoth,
+                            "jump-none" => StepPosition::JumpNone,
+                            _ => return Err(error("unknown step position")),
+                        },
+                    ),
+                    _ => return Err(error("`steps` expects a number of steps and a position")),
+                };
+                let steps = steps
+                    .parse::<u32>()
+                    .map_err(|_| error("the number of steps must be a positive integer"))?;
+                match (steps, position) {
+                    (0, _) => Err(error("the number of steps must be a positive integer")),
+                    (1, StepPosition::JumpNone) => {
+                        Err(error("`jump-none` needs at least two steps"))
+                    }
+                    (steps, position) => Ok(EaseMethod::Steps { steps, position }),
+                }
+            }
+            _ => Err(error("unknown timing function")),
+        }
+    }
+}
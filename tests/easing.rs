@@ -0,0 +1,2240 @@
+use std::time::Duration;
+
+use bevy::{ecs::message::Messages, prelude::*};
+use bevy_easings::{
+    ColorGradient, ColorInterpolation, ColorMix, CustomComponentEase, Ease, EaseFunction,
+    EaseMethod, EasingChainComponent, EasingChainStepCompleted, EasingCompleted, EasingComponent,
+    EasingDirection, EasingGroup, EasingKeyframes, EasingLens, EasingLoopCompleted,
+    EasingPingPongReversed, EasingState, EasingTimeScale, EasingTracks, EasingType, EasingsAppExt,
+    EasingsPlugin, GradientTarget, HueInterpolation, Lerp, LerpColor, Parallel, Repeat,
+    RetargetPolicy, RotationMode, Sequence, Stagger, StaggerDelay, StaggerOrder, StepPosition,
+    Timeline, TimelineTarget, TransformRotationLens, TransformTranslationLens, custom_ease_system,
+};
+
+#[derive(Default, Component, Clone, Copy, Debug)]
+struct Custom(f32);
+
+impl Lerp for Custom {
+    type Scalar = f32;
+
+    fn lerp(&self, other: &Self, scalar: &Self::Scalar) -> Self {
+        Custom(interpolation::lerp(&self.0, &other.0, scalar))
+    }
+}
+
+/// A component eased either with `register_easing` or with `register_custom_easing`, read and written as an `f32`
+trait Eased: Component<Mutability = bevy::ecs::component::Mutable> + Clone + Default {
+    fn new(value: f32) -> Self;
+    fn value(&self) -> f32;
+    fn ease(start: f32, end: f32, easing_type: EasingType) -> EasingComponent<Self>;
+}
+
+impl Eased for Transform {
+    fn new(value: f32) -> Self {
+        Transform::from_xyz(value, 0., 0.)
+    }
+    fn value(&self) -> f32 {
+        self.translation.x
+    }
+    fn ease(start: f32, end: f32, easing_type: EasingType) -> EasingComponent<Self> {
+        Ease::ease_to(
+            Self::new(start),
+            Self::new(end),
+            EaseFunction::QuadraticIn,
+            easing_type,
+        )
+    }
+}
+
+impl Eased for Custom {
+    fn new(value: f32) -> Self {
+        Custom(value)
+    }
+    fn value(&self) -> f32 {
+        self.0
+    }
+    fn ease(start: f32, end: f32, easing_type: EasingType) -> EasingComponent<Self> {
+        CustomComponentEase::ease_to(
+            Self::new(start),
+            Self::new(end),
+            EaseFunction::QuadraticIn,
+            easing_type,
+        )
+    }
+}
+
+#[derive(Resource, Default)]
+struct Completed(u32);
+
+fn app() -> App {
+    let mut app = App::new();
+    app.init_resource::<Time>()
+        .init_resource::<Completed>()
+        .add_plugins(EasingsPlugin::default())
+        .register_custom_easing::<Custom>()
+        .add_observer(
+            |_: On<EasingCompleted<Transform>>, mut completed: ResMut<Completed>| completed.0 += 1,
+        )
+        .add_observer(
+            |_: On<EasingCompleted<Custom>>, mut completed: ResMut<Completed>| completed.0 += 1,
+        );
+    app
+}
+
+fn update(app: &mut App, millis: u64) {
+    app.world_mut()
+        .resource_mut::<Time>()
+        .advance_by(Duration::from_millis(millis));
+    app.update();
+}
+
+fn value<C: Eased>(app: &App, entity: Entity) -> f32 {
+    app.world().get::<C>(entity).unwrap().value()
+}
+
+/// QuadraticIn from 0 to 100
+fn eased(progress: f32) -> f32 {
+    progress * progress * 100.
+}
+
+fn assert_close(a: f32, b: f32) {
+    assert!((a - b).abs() < 0.01, "{a} != {b}");
+}
+
+const SECOND: Duration = Duration::from_secs(1);
+
+fn once<C: Eased>() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn((
+            C::new(0.),
+            C::ease(0., 100., EasingType::Once { duration: SECOND }),
+        ))
+        .id();
+
+    update(&mut app, 250);
+    assert_close(value::<C>(&app, entity), eased(0.25));
+    update(&mut app, 500);
+    assert_close(value::<C>(&app, entity), eased(0.75));
+    assert_eq!(app.world().resource::<Completed>().0, 0);
+
+    update(&mut app, 500);
+    assert_close(value::<C>(&app, entity), 100.);
+    assert!(app.world().get::<EasingComponent<C>>(entity).is_none());
+    assert_eq!(app.world().resource::<Completed>().0, 1);
+}
+
+#[test]
+fn once_builtin() {
+    once::<Transform>();
+}
+
+#[test]
+fn once_custom() {
+    once::<Custom>();
+}
+
+fn pause<C: Eased>() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn((
+            C::new(0.),
+            C::ease(0., 100., EasingType::Once { duration: SECOND }),
+        ))
+        .id();
+
+    update(&mut app, 250);
+    assert_close(value::<C>(&app, entity), eased(0.25));
+
+    app.world_mut()
+        .get_mut::<EasingComponent<C>>(entity)
+        .unwrap()
+        .state = EasingState::Paused;
+    update(&mut app, 500);
+    assert_close(value::<C>(&app, entity), eased(0.25));
+
+    app.world_mut()
+        .get_mut::<EasingComponent<C>>(entity)
+        .unwrap()
+        .state = EasingState::Play;
+    update(&mut app, 250);
+    assert_close(value::<C>(&app, entity), eased(0.5));
+}
+
+#[test]
+fn pause_builtin() {
+    pause::<Transform>();
+}
+
+#[test]
+fn pause_custom() {
+    pause::<Custom>();
+}
+
+fn delay<C: Eased>() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn((
+            C::new(-1.),
+            C::ease(0., 100., EasingType::Once { duration: SECOND })
+                .delay(Duration::from_millis(500)),
+        ))
+        .id();
+
+    update(&mut app, 250);
+    assert_close(value::<C>(&app, entity), -1.);
+    update(&mut app, 250);
+    assert_close(value::<C>(&app, entity), -1.);
+    update(&mut app, 250);
+    assert_close(value::<C>(&app, entity), eased(0.25));
+}
+
+#[test]
+fn delay_builtin() {
+    delay::<Transform>();
+}
+
+#[test]
+fn delay_custom() {
+    delay::<Custom>();
+}
+
+fn looping<C: Eased>() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn((
+            C::new(0.),
+            C::ease(
+                0.,
+                100.,
+                EasingType::Loop {
+                    duration: SECOND,
+                    pause: Some(Duration::from_millis(500)),
+                },
+            ),
+        ))
+        .id();
+
+    update(&mut app, 1000);
+    assert_close(value::<C>(&app, entity), 100.);
+    // during the pause, the value is kept
+    update(&mut app, 250);
+    assert_close(value::<C>(&app, entity), 100.);
+    update(&mut app, 250);
+    update(&mut app, 250);
+    assert_close(value::<C>(&app, entity), eased(0.25));
+    assert!(app.world().get::<EasingComponent<C>>(entity).is_some());
+    assert_eq!(app.world().resource::<Completed>().0, 0);
+}
+
+#[test]
+fn loop_builtin() {
+    looping::<Transform>();
+}
+
+#[test]
+fn loop_custom() {
+    looping::<Custom>();
+}
+
+fn ping_pong<C: Eased>() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn((
+            C::new(0.),
+            C::ease(
+                0.,
+                100.,
+                EasingType::PingPong {
+                    duration: SECOND,
+                    pause: None,
+                },
+            ),
+        ))
+        .id();
+
+    update(&mut app, 1000);
+    assert_close(value::<C>(&app, entity), 100.);
+    assert!(
+        app.world()
+            .get::<EasingComponent<C>>(entity)
+            .unwrap()
+            .direction()
+            == EasingDirection::Backward
+    );
+    update(&mut app, 250);
+    assert_close(value::<C>(&app, entity), eased(0.75));
+    update(&mut app, 750);
+    assert_close(value::<C>(&app, entity), 0.);
+    assert!(
+        app.world()
+            .get::<EasingComponent<C>>(entity)
+            .unwrap()
+            .direction()
+            == EasingDirection::Forward
+    );
+}
+
+#[test]
+fn ping_pong_builtin() {
+    ping_pong::<Transform>();
+}
+
+#[test]
+fn ping_pong_custom() {
+    ping_pong::<Custom>();
+}
+
+fn chain<C: Eased>() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn((
+            C::new(0.),
+            C::ease(0., 100., EasingType::Once { duration: SECOND }).ease_to(
+                C::new(0.),
+                EaseFunction::QuadraticIn,
+                EasingType::Once { duration: SECOND },
+            ),
+        ))
+        .id();
+
+    update(&mut app, 250);
+    assert_close(value::<C>(&app, entity), eased(0.25));
+    // the second easing starts from the current value in the same frame, with the time left
+    update(&mut app, 1000);
+    assert_close(value::<C>(&app, entity), 100. - eased(0.25));
+    assert_eq!(app.world().resource::<Completed>().0, 0);
+    update(&mut app, 250);
+    assert_close(value::<C>(&app, entity), 100. - eased(0.5));
+
+    update(&mut app, 500);
+    assert_close(value::<C>(&app, entity), 0.);
+    assert_eq!(app.world().resource::<Completed>().0, 1);
+    assert!(app.world().get::<EasingComponent<C>>(entity).is_none());
+    assert!(app.world().get::<EasingChainComponent<C>>(entity).is_none());
+}
+
+#[test]
+fn chain_builtin() {
+    chain::<Transform>();
+}
+
+#[test]
+fn chain_custom() {
+    chain::<Custom>();
+}
+
+#[test]
+fn chain_lasts_sum_of_durations() {
+    let mut app = app();
+    let step = EasingType::Once {
+        duration: Duration::from_millis(100),
+    };
+    let mut chain = Custom(0.)
+        .ease_to(Custom(1.), EaseMethod::Linear, step)
+        .ease_to(Custom(2.), EaseMethod::Linear, step);
+    for i in 3..=10 {
+        chain = chain.ease_to(Custom(i as f32), EaseMethod::Linear, step);
+    }
+    let entity = app.world_mut().spawn((Custom(0.), chain)).id();
+
+    for _ in 0..33 {
+        update(&mut app, 30);
+    }
+    assert_eq!(app.world().resource::<Completed>().0, 0);
+    assert_close(value::<Custom>(&app, entity), 9.9);
+    update(&mut app, 30);
+    assert_eq!(app.world().resource::<Completed>().0, 1);
+    assert_close(value::<Custom>(&app, entity), 10.);
+}
+
+/// Easing events received by observers and read as messages
+#[derive(Resource, Default)]
+struct Steps {
+    loops: u32,
+    reversed: Vec<EasingDirection>,
+    chained: Vec<usize>,
+    messages: u32,
+}
+
+fn steps_app() -> App {
+    let mut app = app();
+    app.init_resource::<Steps>()
+        .add_observer(
+            |_: On<EasingLoopCompleted<Transform>>, mut steps: ResMut<Steps>| steps.loops += 1,
+        )
+        .add_observer(
+            |reversed: On<EasingPingPongReversed<Transform>>, mut steps: ResMut<Steps>| {
+                steps.reversed.push(reversed.direction)
+            },
+        )
+        .add_observer(
+            |step: On<EasingChainStepCompleted<Transform>>, mut steps: ResMut<Steps>| {
+                steps.chained.push(step.remaining)
+            },
+        )
+        .add_systems(
+            Last,
+            |mut loops: MessageReader<EasingLoopCompleted<Transform>>,
+             mut reversed: MessageReader<EasingPingPongReversed<Transform>>,
+             mut chained: MessageReader<EasingChainStepCompleted<Transform>>,
+             mut steps: ResMut<Steps>| {
+                steps.messages += (loops.read().count()
+                    + reversed.read().count()
+                    + chained.read().count()) as u32;
+            },
+        );
+    app
+}
+
+#[test]
+fn loop_and_ping_pong_events() {
+    let mut app = steps_app();
+    app.world_mut().spawn((
+        Transform::default(),
+        <Transform as Eased>::ease(
+            0.,
+            100.,
+            EasingType::Loop {
+                duration: SECOND,
+                pause: None,
+            },
+        ),
+    ));
+    app.world_mut().spawn((
+        Transform::default(),
+        <Transform as Eased>::ease(
+            0.,
+            100.,
+            EasingType::PingPong {
+                duration: SECOND,
+                pause: None,
+            },
+        ),
+    ));
+
+    update(&mut app, 500);
+    assert_eq!(app.world().resource::<Steps>().loops, 0);
+    assert!(app.world().resource::<Steps>().reversed.is_empty());
+    update(&mut app, 500);
+    assert_eq!(app.world().resource::<Steps>().loops, 1);
+    assert_eq!(
+        app.world().resource::<Steps>().reversed,
+        [EasingDirection::Backward]
+    );
+    update(&mut app, 1000);
+    let steps = app.world().resource::<Steps>();
+    assert_eq!(steps.loops, 2);
+    assert_eq!(
+        steps.reversed,
+        [EasingDirection::Backward, EasingDirection::Forward]
+    );
+    // each event is also written as a message
+    assert_eq!(steps.messages, 4);
+    assert_eq!(app.world().resource::<Completed>().0, 0);
+}
+
+#[test]
+fn chain_step_events() {
+    let mut app = steps_app();
+    let once = EasingType::Once { duration: SECOND };
+    app.world_mut().spawn((
+        Transform::default(),
+        <Transform as Eased>::ease(0., 100., once)
+            .ease_to(Transform::default(), EaseFunction::QuadraticIn, once)
+            .ease_to(
+                Transform::from_xyz(50., 0., 0.),
+                EaseFunction::QuadraticIn,
+                once,
+            ),
+    ));
+
+    update(&mut app, 1000);
+    assert_eq!(app.world().resource::<Steps>().chained, [2]);
+    update(&mut app, 1000);
+    assert_eq!(app.world().resource::<Steps>().chained, [2, 1]);
+    assert_eq!(app.world().resource::<Completed>().0, 0);
+    update(&mut app, 1000);
+    // the last easing completes the chain instead
+    assert_eq!(app.world().resource::<Steps>().chained, [2, 1]);
+    assert_eq!(app.world().resource::<Steps>().messages, 2);
+    assert_eq!(app.world().resource::<Completed>().0, 1);
+}
+
+#[test]
+fn ease_again_from_completed_observer() {
+    let mut app = app();
+    app.add_observer(
+        |completed: On<EasingCompleted<Transform>>, mut commands: Commands| {
+            commands
+                .entity(completed.entity)
+                .insert(Transform::from_xyz(100., 0., 0.).ease_to(
+                    Transform::default(),
+                    EaseFunction::QuadraticIn,
+                    EasingType::Once { duration: SECOND },
+                ));
+        },
+    );
+    let once = EasingType::Once { duration: SECOND };
+    let single = app
+        .world_mut()
+        .spawn((
+            Transform::default(),
+            <Transform as Eased>::ease(0., 100., once),
+        ))
+        .id();
+    let chained = app
+        .world_mut()
+        .spawn((
+            Transform::default(),
+            <Transform as Eased>::ease(0., 50., once).ease_to(
+                Transform::from_xyz(100., 0., 0.),
+                EaseFunction::QuadraticIn,
+                once,
+            ),
+        ))
+        .id();
+
+    update(&mut app, 1000);
+    update(&mut app, 1000);
+    // the easing inserted by the observer is kept and starts playing
+    for entity in [single, chained] {
+        assert!(
+            app.world()
+                .get::<EasingComponent<Transform>>(entity)
+                .is_some()
+        );
+    }
+    update(&mut app, 500);
+    assert_close(value::<Transform>(&app, single), 100. - eased(0.5));
+}
+
+#[test]
+fn messages_only_when_registered() {
+    let mut app = App::new();
+    app.init_resource::<Time>()
+        .init_resource::<Completed>()
+        .add_plugins(EasingsPlugin::default())
+        .add_systems(Update, custom_ease_system::<(), Custom>)
+        .add_observer(
+            |_: On<EasingCompleted<Custom>>, mut completed: ResMut<Completed>| completed.0 += 1,
+        );
+    app.world_mut().spawn((
+        Custom(0.),
+        <Custom as Eased>::ease(0., 100., EasingType::Once { duration: SECOND }),
+    ));
+
+    update(&mut app, 1000);
+    // observers are triggered, but there is no message to write to
+    assert_eq!(app.world().resource::<Completed>().0, 1);
+    assert!(
+        app.world()
+            .get_resource::<Messages<EasingCompleted<Custom>>>()
+            .is_none()
+    );
+
+    let mut app = self::app();
+    app.world_mut().spawn((
+        Custom(0.),
+        <Custom as Eased>::ease(0., 100., EasingType::Once { duration: SECOND }),
+    ));
+    update(&mut app, 1000);
+    let messages = app.world().resource::<Messages<EasingCompleted<Custom>>>();
+    assert_eq!(messages.iter_current_update_messages().count(), 1);
+}
+
+#[test]
+#[should_panic(expected = "`EasingsPlugin` must be added")]
+fn register_without_plugin() {
+    App::new().register_easing::<Transform>();
+}
+
+#[test]
+fn register_twice() {
+    let mut app = app();
+    app.register_custom_easing::<Custom>()
+        .register_easing::<Transform>();
+    let entity = app
+        .world_mut()
+        .spawn((
+            Custom(0.),
+            <Custom as Eased>::ease(0., 100., EasingType::Once { duration: SECOND }),
+        ))
+        .id();
+
+    update(&mut app, 250);
+    assert_close(value::<Custom>(&app, entity), eased(0.25));
+}
+
+#[test]
+fn register_after_warning() {
+    let mut app = App::new();
+    app.init_resource::<Time>()
+        .add_plugins(EasingsPlugin::default());
+    let entity = app
+        .world_mut()
+        .spawn((
+            Custom(0.),
+            <Custom as Eased>::ease(0., 100., EasingType::Once { duration: SECOND }),
+        ))
+        .id();
+    // warns about `Custom` not being registered
+    update(&mut app, 0);
+
+    app.register_custom_easing::<Custom>();
+    update(&mut app, 250);
+    assert_close(value::<Custom>(&app, entity), eased(0.25));
+}
+
+#[test]
+fn manual_custom_system() {
+    let mut app = App::new();
+    app.init_resource::<Time>()
+        .add_plugins(EasingsPlugin::default())
+        .add_systems(Update, custom_ease_system::<(), Custom>);
+    let entity = app
+        .world_mut()
+        .spawn((
+            Custom(0.),
+            <Custom as Eased>::ease(0., 100., EasingType::Once { duration: SECOND }),
+        ))
+        .id();
+
+    update(&mut app, 250);
+    assert_close(value::<Custom>(&app, entity), eased(0.25));
+}
+
+#[test]
+fn lenses_on_same_component() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn((
+            Transform::from_xyz(0., 5., 0.),
+            Vec3::ZERO
+                .ease_to(
+                    Vec3::new(100., 0., 0.),
+                    EaseFunction::QuadraticIn,
+                    EasingType::Once { duration: SECOND },
+                )
+                .with_lens(TransformTranslationLens),
+            Quat::IDENTITY
+                .ease_to(
+                    Quat::from_rotation_z(1.),
+                    EaseFunction::QuadraticIn,
+                    EasingType::Once { duration: SECOND },
+                )
+                .with_lens(TransformRotationLens),
+        ))
+        .id();
+
+    update(&mut app, 250);
+    let transform = app.world().get::<Transform>(entity).unwrap();
+    assert_close(transform.translation.x, eased(0.25));
+    assert_close(transform.translation.y, 0.);
+    assert!(transform.rotation.angle_between(Quat::IDENTITY) > 0.);
+
+    update(&mut app, 1000);
+    let transform = app.world().get::<Transform>(entity).unwrap();
+    assert_close(transform.translation.x, 100.);
+    assert!(transform.rotation.angle_between(Quat::from_rotation_z(1.)) < 0.01);
+    assert!(
+        app.world()
+            .get::<EasingLens<TransformTranslationLens>>(entity)
+            .is_none()
+    );
+}
+
+#[test]
+fn lens_starts_from_current_value() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn((
+            Transform::from_xyz(20., 0., 0.).with_scale(Vec3::splat(2.)),
+            <Vec3 as Ease>::ease(
+                None,
+                Vec3::new(100., 0., 0.),
+                EaseFunction::QuadraticIn,
+                EasingType::Once { duration: SECOND },
+            )
+            .with_lens(TransformTranslationLens),
+        ))
+        .id();
+
+    update(&mut app, 500);
+    let transform = app.world().get::<Transform>(entity).unwrap();
+    assert_close(transform.translation.x, 20. + 80. * eased(0.5) / 100.);
+    assert_close(transform.scale.x, 2.);
+}
+
+#[test]
+fn additive_tracks() {
+    let mut app = app();
+    app.add_observer(
+        |_: On<EasingCompleted<EasingTracks<Transform>>>, mut completed: ResMut<Completed>| {
+            completed.0 += 1
+        },
+    );
+    let transform = Transform::from_xyz(0., 0., 5.);
+    let entity = app
+        .world_mut()
+        .spawn((
+            transform,
+            EasingTracks::new()
+                .with(
+                    transform
+                        .ease_to(
+                            transform.with_translation(Vec3::new(100., 0., 5.)),
+                            EaseFunction::QuadraticIn,
+                            EasingType::Once { duration: SECOND },
+                        )
+                        .additive(),
+                )
+                .with(
+                    transform
+                        .ease_to(
+                            transform.with_translation(Vec3::new(0., 50., 5.)),
+                            EaseFunction::QuadraticIn,
+                            EasingType::Once {
+                                duration: SECOND * 2,
+                            },
+                        )
+                        .additive(),
+                ),
+        ))
+        .id();
+
+    update(&mut app, 500);
+    let transform = app.world().get::<Transform>(entity).unwrap();
+    assert_close(transform.translation.x, eased(0.5));
+    assert_close(transform.translation.y, eased(0.25) / 2.);
+    assert_close(transform.translation.z, 5.);
+
+    update(&mut app, 500);
+    assert_eq!(
+        app.world()
+            .get::<EasingTracks<Transform>>(entity)
+            .unwrap()
+            .tracks()
+            .len(),
+        1
+    );
+    update(&mut app, 1000);
+    let transform = app.world().get::<Transform>(entity).unwrap();
+    assert_close(transform.translation.x, 100.);
+    assert_close(transform.translation.y, 50.);
+    assert!(app.world().get::<EasingTracks<Transform>>(entity).is_none());
+    assert_eq!(app.world().resource::<Completed>().0, 1);
+}
+
+#[test]
+fn weighted_easing() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn((
+            Transform::default(),
+            Transform::default()
+                .ease_to(
+                    Transform::from_xyz(100., 0., 0.),
+                    EaseFunction::QuadraticIn,
+                    EasingType::Once { duration: SECOND },
+                )
+                .weighted(0.5),
+        ))
+        .id();
+
+    update(&mut app, 500);
+    assert_close(value::<Transform>(&app, entity), eased(0.5) / 2.);
+}
+
+#[test]
+fn weighted_easing_independent_of_step_size() {
+    let weighted = || {
+        (
+            Transform::default(),
+            Transform::default()
+                .ease_to(
+                    Transform::from_xyz(100., 0., 0.),
+                    EaseFunction::QuadraticIn,
+                    EasingType::Once { duration: SECOND },
+                )
+                .weighted(0.5),
+        )
+    };
+    let mut coarse = app();
+    let coarse_entity = coarse.world_mut().spawn(weighted()).id();
+    let mut fine = app();
+    let fine_entity = fine.world_mut().spawn(weighted()).id();
+
+    update(&mut coarse, 500);
+    for _ in 0..10 {
+        update(&mut fine, 50);
+    }
+    assert_close(value::<Transform>(&coarse, coarse_entity), eased(0.5) / 2.);
+    assert_close(value::<Transform>(&fine, fine_entity), eased(0.5) / 2.);
+
+    update(&mut coarse, 500);
+    for _ in 0..10 {
+        update(&mut fine, 50);
+    }
+    assert_close(value::<Transform>(&coarse, coarse_entity), 50.);
+    assert_close(value::<Transform>(&fine, fine_entity), 50.);
+}
+
+#[test]
+fn ease_by_composes_with_other_changes() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn((
+            Transform::from_xyz(10., 0., 0.),
+            Transform::ease_by(
+                Transform::from_xyz(100., 0., 0.),
+                EaseFunction::QuadraticIn,
+                EasingType::Once { duration: SECOND },
+            ),
+        ))
+        .id();
+
+    update(&mut app, 500);
+    assert_close(value::<Transform>(&app, entity), 10. + eased(0.5));
+
+    // another system moves the entity while it is easing
+    app.world_mut()
+        .get_mut::<Transform>(entity)
+        .unwrap()
+        .translation
+        .x += 5.;
+    update(&mut app, 500);
+    assert_close(value::<Transform>(&app, entity), 115.);
+}
+
+#[test]
+fn additive_scale_from_zero() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn((
+            Transform::from_scale(Vec3::ZERO),
+            Transform::from_scale(Vec3::ZERO)
+                .ease_to(
+                    Transform::default(),
+                    EaseFunction::QuadraticIn,
+                    EasingType::Once { duration: SECOND },
+                )
+                .additive(),
+        ))
+        .id();
+
+    update(&mut app, 500);
+    let scale = app.world().get::<Transform>(entity).unwrap().scale;
+    assert!(scale.is_finite());
+    assert_close(scale.x, eased(0.5) / 100.);
+    update(&mut app, 500);
+    assert_close(app.world().get::<Transform>(entity).unwrap().scale.x, 1.);
+}
+
+#[test]
+fn chain_ease_to_fn_from_previous_end() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn((
+            Transform::default(),
+            Transform::default()
+                .ease_to(
+                    Transform::from_xyz(100., 0., 0.),
+                    EaseFunction::QuadraticIn,
+                    EasingType::Once { duration: SECOND },
+                )
+                .ease_to_fn(
+                    |previous| Transform::from_xyz(previous.translation.x + 50., 0., 0.),
+                    EaseFunction::QuadraticIn,
+                    EasingType::Once { duration: SECOND },
+                )
+                .ease_to_fn(
+                    |previous| Transform::from_xyz(previous.translation.x + 50., 0., 0.),
+                    EaseFunction::QuadraticIn,
+                    EasingType::Once { duration: SECOND },
+                ),
+        ))
+        .id();
+
+    for _ in 0..12 {
+        update(&mut app, 500);
+    }
+    assert_close(value::<Transform>(&app, entity), 200.);
+}
+
+fn custom_ease(end: f32, method: EaseMethod) -> EasingComponent<Custom> {
+    CustomComponentEase::ease_to(
+        Custom(0.),
+        Custom(end),
+        method,
+        EasingType::Once { duration: SECOND },
+    )
+}
+
+#[test]
+fn spring_settles_on_target() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn((
+            Custom(0.),
+            custom_ease(
+                100.,
+                EaseMethod::Spring {
+                    stiffness: 100.,
+                    damping: 5.,
+                    mass: 1.,
+                },
+            ),
+        ))
+        .id();
+
+    let mut highest: f32 = 0.;
+    for _ in 0..100 {
+        update(&mut app, 50);
+        highest = highest.max(value::<Custom>(&app, entity));
+    }
+    // an underdamped spring overshoots its target
+    assert!(highest > 100.);
+    assert_eq!(value::<Custom>(&app, entity), 100.);
+    assert!(app.world().get::<EasingComponent<Custom>>(entity).is_none());
+    assert_eq!(app.world().resource::<Completed>().0, 1);
+}
+
+#[test]
+fn spring_retarget_keeps_velocity() {
+    let mut app = app();
+    let method = EaseMethod::SmoothDamp {
+        smooth_time: Duration::from_millis(500),
+    };
+    let retargeted = app
+        .world_mut()
+        .spawn((Custom(0.), custom_ease(100., method.clone())))
+        .id();
+    let reference = app
+        .world_mut()
+        .spawn((Custom(0.), custom_ease(100., method)))
+        .id();
+
+    update(&mut app, 200);
+    let before = value::<Custom>(&app, retargeted);
+    assert!(before > 0. && before < 100.);
+    app.world_mut()
+        .get_mut::<EasingComponent<Custom>>(retargeted)
+        .unwrap()
+        .retarget(Custom(100.), RetargetPolicy::Restart);
+
+    // retargeting to the same value doesn't change the motion
+    for _ in 0..5 {
+        update(&mut app, 50);
+        assert_close(
+            value::<Custom>(&app, retargeted),
+            value::<Custom>(&app, reference),
+        );
+    }
+}
+
+fn eased_with(method: &str, millis: u64) -> f32 {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn((
+            Custom(0.),
+            CustomComponentEase::ease_to(
+                Custom(0.),
+                Custom(100.),
+                method.parse::<EaseMethod>().unwrap(),
+                EasingType::Once { duration: SECOND },
+            ),
+        ))
+        .id();
+    update(&mut app, millis);
+    value::<Custom>(&app, entity)
+}
+
+#[test]
+fn css_timing_functions() {
+    assert_close(eased_with("cubic-bezier(0, 0, 1, 1)", 300), 30.);
+    assert_close(eased_with("linear", 5), 0.5);
+    assert_close(eased_with("linear", 995), 99.5);
+    assert_close(eased_with("ease", 500), 80.24);
+    assert_close(eased_with("ease-in-out", 500), 50.);
+
+    assert_close(eased_with("steps(4)", 300), 25.);
+    assert_close(eased_with("steps(4, jump-start)", 300), 50.);
+    assert_close(eased_with("steps(4, jump-both)", 300), 40.);
+    assert_close(eased_with("steps(5, jump-none)", 300), 25.);
+    assert_close(eased_with("step-start", 100), 100.);
+    assert_close(eased_with("step-end", 900), 0.);
+
+    assert!("cubic-bezier(1.5, 0, 1, 1)".parse::<EaseMethod>().is_err());
+    assert!("steps(1, jump-none)".parse::<EaseMethod>().is_err());
+    assert!("steps(2, sideways)".parse::<EaseMethod>().is_err());
+    assert!("bounce".parse::<EaseMethod>().is_err());
+}
+
+#[test]
+fn closure_ease_method() {
+    let mut app = app();
+    let steepness = 2.;
+    let method = EaseMethod::closure(move |x| (x * steepness).min(1.));
+    let entity = app
+        .world_mut()
+        .spawn((Custom(0.), custom_ease(100., method.clone())))
+        .id();
+    let other = app
+        .world_mut()
+        .spawn((Custom(0.), custom_ease(10., method)))
+        .id();
+
+    update(&mut app, 250);
+    assert_close(value::<Custom>(&app, entity), 50.);
+    assert_close(value::<Custom>(&app, other), 5.);
+    update(&mut app, 500);
+    assert_close(value::<Custom>(&app, entity), 100.);
+}
+
+fn keyframes() -> EasingKeyframes<Custom> {
+    EasingKeyframes::new()
+        .keyframe(Duration::from_millis(500), Custom(0.), EaseMethod::Linear)
+        .keyframe(SECOND, Custom(100.), EaseFunction::QuadraticIn)
+        .keyframe(
+            SECOND * 2,
+            Custom(50.),
+            EaseMethod::Steps {
+                steps: 2,
+                position: StepPosition::JumpEnd,
+            },
+        )
+}
+
+#[test]
+fn keyframes_once() {
+    let mut app = app();
+    app.add_observer(
+        |_: On<EasingCompleted<EasingKeyframes<Custom>>>, mut completed: ResMut<Completed>| {
+            completed.0 += 1
+        },
+    );
+    let entity = app.world_mut().spawn((Custom(-1.), keyframes())).id();
+
+    // the value of the first keyframe is held until its time
+    update(&mut app, 250);
+    assert_close(value::<Custom>(&app, entity), 0.);
+    update(&mut app, 500);
+    assert_close(value::<Custom>(&app, entity), eased(0.5));
+    // next keyframe starts right away, with its own ease method
+    update(&mut app, 500);
+    assert_close(value::<Custom>(&app, entity), 100.);
+    update(&mut app, 500);
+    assert_close(value::<Custom>(&app, entity), 75.);
+    assert_eq!(app.world().resource::<Completed>().0, 0);
+
+    update(&mut app, 500);
+    assert_close(value::<Custom>(&app, entity), 50.);
+    assert!(app.world().get::<EasingKeyframes<Custom>>(entity).is_none());
+    assert_eq!(app.world().resource::<Completed>().0, 1);
+}
+
+#[test]
+fn keyframes_sample_and_scrub() {
+    let track = keyframes();
+    assert_close(track.sample_custom(Duration::ZERO).unwrap().0, 0.);
+    assert_close(
+        track.sample_custom(Duration::from_millis(750)).unwrap().0,
+        eased(0.5),
+    );
+    assert_close(track.sample_custom(SECOND * 3).unwrap().0, 50.);
+    assert!(
+        EasingKeyframes::<Custom>::new()
+            .sample_custom(SECOND)
+            .is_none()
+    );
+
+    let mut app = app();
+    let mut track = keyframes();
+    track.state = EasingState::Paused;
+    let entity = app.world_mut().spawn((Custom(-1.), track)).id();
+    update(&mut app, 250);
+    assert_close(value::<Custom>(&app, entity), -1.);
+
+    app.world_mut()
+        .get_mut::<EasingKeyframes<Custom>>(entity)
+        .unwrap()
+        .set_elapsed(Duration::from_millis(750));
+    update(&mut app, 250);
+    assert_close(value::<Custom>(&app, entity), eased(0.5));
+}
+
+#[test]
+fn keyframes_ping_pong() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn((Custom(0.), keyframes().ping_pong()))
+        .id();
+
+    update(&mut app, 2000);
+    assert_close(value::<Custom>(&app, entity), 50.);
+    update(&mut app, 1250);
+    assert_close(value::<Custom>(&app, entity), eased(0.5));
+    update(&mut app, 750);
+    assert_close(value::<Custom>(&app, entity), 0.);
+    update(&mut app, 750);
+    assert_close(value::<Custom>(&app, entity), eased(0.5));
+}
+
+#[test]
+fn keyframes_several_periods_in_one_update() {
+    #[derive(Resource, Default)]
+    struct KeyframesSteps {
+        loops: u32,
+        reversed: Vec<EasingDirection>,
+    }
+    let linear = || {
+        EasingKeyframes::new()
+            .keyframe(Duration::ZERO, Custom(0.), EaseMethod::Linear)
+            .keyframe(SECOND, Custom(100.), EaseMethod::Linear)
+    };
+    let mut app = app();
+    app.init_resource::<KeyframesSteps>()
+        .add_observer(
+            |_: On<EasingLoopCompleted<EasingKeyframes<Custom>>>,
+             mut steps: ResMut<KeyframesSteps>| steps.loops += 1,
+        )
+        .add_observer(
+            |reversed: On<EasingPingPongReversed<EasingKeyframes<Custom>>>,
+             mut steps: ResMut<KeyframesSteps>| steps.reversed.push(reversed.direction),
+        );
+    let looping = app.world_mut().spawn((Custom(0.), linear().looping())).id();
+    let ping_pong = app
+        .world_mut()
+        .spawn((Custom(0.), linear().ping_pong()))
+        .id();
+
+    update(&mut app, 2250);
+    assert_close(value::<Custom>(&app, looping), 25.);
+    assert_close(value::<Custom>(&app, ping_pong), 25.);
+    let steps = app.world().resource::<KeyframesSteps>();
+    assert_eq!(steps.loops, 2);
+    assert_eq!(
+        steps.reversed,
+        vec![EasingDirection::Backward, EasingDirection::Forward]
+    );
+
+    update(&mut app, 1000);
+    assert_close(value::<Custom>(&app, looping), 25.);
+    assert_close(value::<Custom>(&app, ping_pong), 75.);
+}
+
+#[test]
+fn group_across_components() {
+    let mut app = app();
+    app.add_observer(
+        |_: On<EasingCompleted<EasingGroup>>, mut completed: ResMut<Completed>| completed.0 += 1,
+    );
+    let entity = app
+        .world_mut()
+        .spawn((
+            Transform::default(),
+            Custom(0.),
+            EasingGroup::from(
+                Sequence::new()
+                    .then(
+                        Parallel::new()
+                            .with(<Transform as Eased>::ease(
+                                0.,
+                                100.,
+                                EasingType::Once { duration: SECOND },
+                            ))
+                            .with(<Custom as Eased>::ease(
+                                0.,
+                                100.,
+                                EasingType::Once {
+                                    duration: SECOND / 2,
+                                },
+                            )),
+                    )
+                    .then(<Custom as Eased>::ease(
+                        100.,
+                        0.,
+                        EasingType::Once { duration: SECOND },
+                    )),
+            ),
+        ))
+        .id();
+
+    update(&mut app, 500);
+    assert_close(value::<Transform>(&app, entity), eased(0.5));
+    assert_close(value::<Custom>(&app, entity), 100.);
+    // the next step waits for both easings
+    update(&mut app, 250);
+    assert_close(value::<Transform>(&app, entity), eased(0.75));
+    assert_close(value::<Custom>(&app, entity), 100.);
+    update(&mut app, 500);
+    assert_close(value::<Transform>(&app, entity), 100.);
+    assert_close(value::<Custom>(&app, entity), 100. - eased(0.25));
+    assert_eq!(app.world().resource::<Completed>().0, 0);
+
+    update(&mut app, 750);
+    assert_close(value::<Custom>(&app, entity), 0.);
+    assert!(app.world().get::<EasingGroup>(entity).is_none());
+    assert_eq!(app.world().resource::<Completed>().0, 1);
+}
+
+#[test]
+fn group_stagger() {
+    let mut app = app();
+    let easing = || {
+        Vec3::ZERO.ease_to(
+            Vec3::new(100., 0., 0.),
+            EaseFunction::QuadraticIn,
+            EasingType::Once { duration: SECOND },
+        )
+    };
+    let entity = app
+        .world_mut()
+        .spawn((
+            Transform::default(),
+            Custom(0.),
+            EasingGroup::from(
+                Stagger::new(SECOND / 2)
+                    .with(<Custom as Eased>::ease(
+                        0.,
+                        100.,
+                        EasingType::Once { duration: SECOND },
+                    ))
+                    .with(easing().with_lens(TransformTranslationLens)),
+            ),
+        ))
+        .id();
+
+    update(&mut app, 750);
+    assert_close(value::<Custom>(&app, entity), eased(0.75));
+    assert_close(value::<Transform>(&app, entity), eased(0.25));
+}
+
+#[derive(Component)]
+struct Marker;
+
+#[test]
+fn timeline_across_entities() {
+    let mut app = app();
+    app.add_observer(
+        |_: On<EasingCompleted<Timeline>>, mut completed: ResMut<Completed>| completed.0 += 1,
+    );
+    let first = app.world_mut().spawn(Transform::default()).id();
+    let marked = [
+        app.world_mut().spawn((Custom(0.), Marker)).id(),
+        app.world_mut().spawn((Custom(0.), Marker)).id(),
+    ];
+    let named = app
+        .world_mut()
+        .spawn((Transform::default(), Name::new("named")))
+        .id();
+    let timeline = app
+        .world_mut()
+        .spawn(
+            Timeline::new()
+                .add(
+                    first,
+                    Duration::ZERO,
+                    <Transform as Eased>::ease(0., 100., EasingType::Once { duration: SECOND }),
+                )
+                .add(
+                    TimelineTarget::marker::<Marker>(),
+                    SECOND / 2,
+                    <Custom as Eased>::ease(0., 100., EasingType::Once { duration: SECOND }),
+                )
+                .add(
+                    Name::new("named"),
+                    SECOND,
+                    // a delay is added to the offset
+                    <Transform as Eased>::ease(0., 100., EasingType::Once { duration: SECOND })
+                        .delay(SECOND / 2),
+                ),
+        )
+        .id();
+    assert_eq!(
+        app.world().get::<Timeline>(timeline).unwrap().duration(),
+        SECOND * 5 / 2
+    );
+
+    update(&mut app, 750);
+    assert_close(value::<Transform>(&app, first), eased(0.75));
+    for entity in marked {
+        assert_close(value::<Custom>(&app, entity), eased(0.25));
+    }
+    assert_close(value::<Transform>(&app, named), 0.);
+
+    update(&mut app, 1000);
+    update(&mut app, 1000);
+    assert_close(value::<Transform>(&app, first), 100.);
+    assert_close(value::<Transform>(&app, named), eased(1.));
+    assert_eq!(app.world().resource::<Completed>().0, 1);
+    assert_eq!(
+        app.world().get::<Timeline>(timeline).unwrap().state,
+        EasingState::Paused
+    );
+
+    // played backward, entities go back to their start values
+    app.world_mut()
+        .get_mut::<Timeline>(timeline)
+        .unwrap()
+        .reverse();
+    update(&mut app, 1250);
+    assert_close(value::<Transform>(&app, named), 0.);
+    for entity in marked {
+        assert_close(value::<Custom>(&app, entity), eased(0.75));
+    }
+    update(&mut app, 1500);
+    assert_close(value::<Transform>(&app, first), 0.);
+    for entity in marked {
+        assert_close(value::<Custom>(&app, entity), 0.);
+    }
+    assert_eq!(app.world().resource::<Completed>().0, 2);
+}
+
+#[test]
+fn timeline_seek_and_labels() {
+    let mut app = app();
+    let entity = app.world_mut().spawn(Transform::default()).id();
+    let mut timeline = Timeline::new()
+        .add(
+            entity,
+            Duration::ZERO,
+            <Transform as Eased>::ease(0., 100., EasingType::Once { duration: SECOND }),
+        )
+        .add(
+            entity,
+            SECOND,
+            <Transform as Eased>::ease(100., 0., EasingType::Once { duration: SECOND }),
+        )
+        .label("intro", Duration::ZERO)
+        .label("outro", SECOND);
+    timeline.pause();
+    let timeline = app.world_mut().spawn(timeline).id();
+
+    // seeking updates entities even when paused
+    app.world_mut()
+        .get_mut::<Timeline>(timeline)
+        .unwrap()
+        .seek(SECOND / 2);
+    update(&mut app, 100);
+    assert_close(value::<Transform>(&app, entity), eased(0.5));
+    update(&mut app, 100);
+    assert_close(value::<Transform>(&app, entity), eased(0.5));
+
+    // playing from a label stops at the next one
+    assert!(
+        app.world_mut()
+            .get_mut::<Timeline>(timeline)
+            .unwrap()
+            .play_label("intro")
+    );
+    update(&mut app, 750);
+    assert_close(value::<Transform>(&app, entity), eased(0.75));
+    update(&mut app, 750);
+    assert_close(value::<Transform>(&app, entity), 100.);
+    let time = app.world().get::<Timeline>(timeline).unwrap().elapsed();
+    assert_eq!(time, SECOND);
+
+    assert!(
+        app.world_mut()
+            .get_mut::<Timeline>(timeline)
+            .unwrap()
+            .play_label("outro")
+    );
+    update(&mut app, 500);
+    assert_close(value::<Transform>(&app, entity), 100. - eased(0.5));
+    assert!(
+        !app.world_mut()
+            .get_mut::<Timeline>(timeline)
+            .unwrap()
+            .seek_label("credits")
+    );
+}
+
+#[test]
+fn stagger_delays() {
+    let millis = |stagger: StaggerDelay, count| {
+        stagger
+            .delays(count)
+            .into_iter()
+            .map(|delay| delay.as_millis())
+            .collect::<Vec<_>>()
+    };
+    let each = Duration::from_millis(100);
+
+    assert_eq!(millis(StaggerDelay::new(each), 4), [0, 100, 200, 300]);
+    assert_eq!(
+        millis(StaggerDelay::new(each).order(StaggerOrder::Reverse), 4),
+        [300, 200, 100, 0]
+    );
+    assert_eq!(
+        millis(StaggerDelay::new(each).order(StaggerOrder::FromCenter), 5),
+        [200, 100, 0, 100, 200]
+    );
+    assert_eq!(
+        millis(StaggerDelay::new(each).order(StaggerOrder::FromEdges), 5),
+        [0, 100, 200, 100, 0]
+    );
+    assert_eq!(
+        millis(
+            StaggerDelay::new(each).order(StaggerOrder::Grid {
+                columns: 3,
+                origin: (0, 0),
+            }),
+            6
+        ),
+        [0, 100, 200, 100, 141, 223]
+    );
+    // the distribution of delays is eased
+    assert_eq!(
+        millis(StaggerDelay::new(each).ease(EaseFunction::QuadraticIn), 5),
+        [0, 25, 100, 225, 400]
+    );
+    assert_eq!(StaggerDelay::new(each).delay(3, 5).as_millis(), 300);
+
+    // a random order is a permutation, always the same for a seed
+    let random = |seed| StaggerDelay::new(each).order(StaggerOrder::Random { seed });
+    let mut delays = millis(random(7), 10);
+    assert_eq!(delays, millis(random(7), 10));
+    assert_ne!(delays, millis(random(8), 10));
+    delays.sort();
+    assert_eq!(delays, millis(StaggerDelay::new(each), 10));
+}
+
+#[test]
+fn seek_and_progress() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn((
+            Transform::default(),
+            <Transform as Eased>::ease(0., 100., EasingType::Once { duration: SECOND })
+                .delay(SECOND),
+        ))
+        .id();
+    fn easing(app: &mut App, entity: Entity) -> Mut<'_, EasingComponent<Transform>> {
+        app.world_mut()
+            .get_mut::<EasingComponent<Transform>>(entity)
+            .unwrap()
+    }
+
+    update(&mut app, 500);
+    assert_eq!(easing(&mut app, entity).progress(), 0.);
+    assert_eq!(easing(&mut app, entity).remaining(), SECOND * 3 / 2);
+
+    // a paused easing is updated once moved in time, skipping its delay
+    easing(&mut app, entity).state = EasingState::Paused;
+    easing(&mut app, entity).set_progress(0.5);
+    update(&mut app, 100);
+    assert_close(value::<Transform>(&app, entity), eased(0.5));
+    assert_eq!(easing(&mut app, entity).elapsed(), SECOND / 2);
+    assert_eq!(easing(&mut app, entity).remaining(), SECOND / 2);
+    easing(&mut app, entity).seek(SECOND / 4);
+    update(&mut app, 100);
+    assert_close(value::<Transform>(&app, entity), eased(0.25));
+    update(&mut app, 100);
+    assert_close(value::<Transform>(&app, entity), eased(0.25));
+
+    easing(&mut app, entity).restart();
+    update(&mut app, 100);
+    assert_close(value::<Transform>(&app, entity), 0.);
+
+    easing(&mut app, entity).state = EasingState::Play;
+    easing(&mut app, entity).seek(SECOND * 3 / 4);
+    update(&mut app, 100);
+    assert_close(value::<Transform>(&app, entity), eased(0.85));
+    assert_close(easing(&mut app, entity).progress(), 0.85);
+    update(&mut app, 150);
+    assert_close(value::<Transform>(&app, entity), 100.);
+    assert!(
+        app.world()
+            .get::<EasingComponent<Transform>>(entity)
+            .is_none()
+    );
+}
+
+#[test]
+fn speed_and_time_scale() {
+    let mut app = app();
+    let ease = |speed| {
+        <Transform as Eased>::ease(0., 100., EasingType::Once { duration: SECOND })
+            .with_speed(speed)
+    };
+    let fast = app.world_mut().spawn((Transform::default(), ease(2.))).id();
+    let backward = app
+        .world_mut()
+        .spawn((Transform::default(), ease(-1.)))
+        .id();
+    let rewound = app.world_mut().spawn((Transform::default(), ease(1.))).id();
+
+    update(&mut app, 250);
+    assert_close(value::<Transform>(&app, fast), eased(0.5));
+    assert_close(value::<Transform>(&app, backward), eased(0.75));
+    assert_close(value::<Transform>(&app, rewound), eased(0.25));
+
+    // the time scale applies to all easings
+    app.world_mut().resource_mut::<EasingTimeScale>().0 = 0.5;
+    app.world_mut()
+        .get_mut::<EasingComponent<Transform>>(rewound)
+        .unwrap()
+        .speed = -1.;
+    update(&mut app, 200);
+    assert_close(value::<Transform>(&app, fast), eased(0.7));
+    assert_close(value::<Transform>(&app, backward), eased(0.65));
+    assert_close(value::<Transform>(&app, rewound), eased(0.15));
+
+    // a `Once` easing played backward completes at its start
+    update(&mut app, 400);
+    assert_close(value::<Transform>(&app, rewound), 0.);
+    assert!(
+        app.world()
+            .get::<EasingComponent<Transform>>(rewound)
+            .is_none()
+    );
+}
+
+#[test]
+fn ping_pong_backward() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn((
+            Transform::default(),
+            <Transform as Eased>::ease(
+                0.,
+                100.,
+                EasingType::PingPong {
+                    duration: SECOND,
+                    pause: None,
+                },
+            )
+            .with_speed(-1.),
+        ))
+        .id();
+
+    update(&mut app, 500);
+    assert_close(value::<Transform>(&app, entity), eased(0.5));
+    update(&mut app, 500);
+    assert_close(value::<Transform>(&app, entity), 0.);
+    // played backward from the start, it goes toward the end in the other direction
+    update(&mut app, 250);
+    assert_close(value::<Transform>(&app, entity), eased(0.25));
+    assert_eq!(
+        app.world()
+            .get::<EasingComponent<Transform>>(entity)
+            .unwrap()
+            .direction(),
+        EasingDirection::Backward
+    );
+}
+
+#[test]
+fn reverse_in_place() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn((
+            Transform::default(),
+            <Transform as Eased>::ease(0., 100., EasingType::Once { duration: SECOND }),
+        ))
+        .id();
+
+    update(&mut app, 250);
+    assert_close(value::<Transform>(&app, entity), eased(0.25));
+    app.world_mut()
+        .get_mut::<EasingComponent<Transform>>(entity)
+        .unwrap()
+        .reverse();
+    // the easing goes back along the same curve, with no jump
+    update(&mut app, 100);
+    assert_close(value::<Transform>(&app, entity), eased(0.15));
+    assert_eq!(app.world().resource::<Completed>().0, 0);
+    update(&mut app, 200);
+    assert_close(value::<Transform>(&app, entity), 0.);
+    assert_eq!(app.world().resource::<Completed>().0, 1);
+    assert!(
+        app.world()
+            .get::<EasingComponent<Transform>>(entity)
+            .is_none()
+    );
+}
+
+#[test]
+fn toggle_back() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn((
+            Transform::default(),
+            <Transform as Eased>::ease(0., 100., EasingType::Once { duration: SECOND }),
+        ))
+        .id();
+
+    update(&mut app, 500);
+    assert_close(value::<Transform>(&app, entity), 25.);
+    app.world_mut()
+        .get_mut::<EasingComponent<Transform>>(entity)
+        .unwrap()
+        .toggle();
+    // eases from the current value back to the start, in the time it took to get there
+    update(&mut app, 250);
+    assert_close(value::<Transform>(&app, entity), 25. - 25. * 0.25);
+    update(&mut app, 250);
+    assert_close(value::<Transform>(&app, entity), 0.);
+    assert!(
+        app.world()
+            .get::<EasingComponent<Transform>>(entity)
+            .is_none()
+    );
+}
+
+#[test]
+fn set_progress_after_toggle() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn((
+            Transform::default(),
+            <Transform as Eased>::ease(0., 100., EasingType::Once { duration: SECOND }),
+        ))
+        .id();
+    fn easing(app: &mut App, entity: Entity) -> Mut<'_, EasingComponent<Transform>> {
+        app.world_mut()
+            .get_mut::<EasingComponent<Transform>>(entity)
+            .unwrap()
+    }
+
+    update(&mut app, 500);
+    easing(&mut app, entity).toggle();
+    update(&mut app, 100);
+    // the movement back lasts half a second
+    easing(&mut app, entity).set_progress(0.5);
+    assert_close(easing(&mut app, entity).progress(), 0.5);
+    assert_eq!(easing(&mut app, entity).remaining(), SECOND / 4);
+    update(&mut app, 0);
+    assert_close(value::<Transform>(&app, entity), 25. - 25. * 0.25);
+}
+
+#[test]
+fn finite_repeats() {
+    let mut app = app();
+    let looping = app
+        .world_mut()
+        .spawn((
+            Transform::default(),
+            <Transform as Eased>::ease(
+                0.,
+                100.,
+                EasingType::Loop {
+                    duration: SECOND,
+                    pause: None,
+                },
+            )
+            .repeat(Repeat::Times(3)),
+        ))
+        .id();
+    let ping_pong = app
+        .world_mut()
+        .spawn((
+            Custom(0.),
+            <Custom as Eased>::ease(
+                0.,
+                100.,
+                EasingType::PingPong {
+                    duration: SECOND,
+                    pause: Some(SECOND / 2),
+                },
+            )
+            .repeat(Repeat::For(SECOND * 2)),
+        ))
+        .id();
+
+    for _ in 0..4 {
+        update(&mut app, 500);
+    }
+    assert_close(value::<Custom>(&app, ping_pong), eased(0.5));
+    assert_eq!(app.world().resource::<Completed>().0, 0);
+    // no iteration starts once the time elapsed
+    update(&mut app, 500);
+    assert_close(value::<Custom>(&app, ping_pong), 0.);
+    assert!(
+        app.world()
+            .get::<EasingComponent<Custom>>(ping_pong)
+            .is_none()
+    );
+    assert_eq!(app.world().resource::<Completed>().0, 1);
+
+    assert_close(value::<Transform>(&app, looping), eased(0.5));
+    assert_eq!(
+        app.world()
+            .get::<EasingComponent<Transform>>(looping)
+            .unwrap()
+            .iterations(),
+        2
+    );
+    // the last iteration ends on the end value, and the easing completes
+    update(&mut app, 500);
+    assert_close(value::<Transform>(&app, looping), 100.);
+    assert!(
+        app.world()
+            .get::<EasingComponent<Transform>>(looping)
+            .is_none()
+    );
+    assert_eq!(app.world().resource::<Completed>().0, 2);
+}
+
+#[test]
+fn restart_resets_repeats() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn((
+            Transform::default(),
+            <Transform as Eased>::ease(
+                0.,
+                100.,
+                EasingType::Loop {
+                    duration: SECOND,
+                    pause: None,
+                },
+            )
+            .repeat(Repeat::Times(2)),
+        ))
+        .id();
+    let easing = |app: &mut App| {
+        app.world_mut()
+            .get_mut::<EasingComponent<Transform>>(entity)
+            .map(|easing| easing.iterations())
+    };
+
+    update(&mut app, 1000);
+    update(&mut app, 500);
+    assert_eq!(easing(&mut app), Some(1));
+    app.world_mut()
+        .get_mut::<EasingComponent<Transform>>(entity)
+        .unwrap()
+        .restart();
+    assert_eq!(easing(&mut app), Some(0));
+
+    // both iterations are played again
+    update(&mut app, 1000);
+    assert_eq!(easing(&mut app), Some(1));
+    update(&mut app, 500);
+    assert_close(value::<Transform>(&app, entity), eased(0.5));
+    update(&mut app, 500);
+    assert_eq!(easing(&mut app), None);
+    assert_eq!(app.world().resource::<Completed>().0, 1);
+}
+
+#[test]
+fn retarget_policies() {
+    let mut app = app();
+    let policies = [
+        RetargetPolicy::Restart,
+        RetargetPolicy::KeepRemainingTime,
+        RetargetPolicy::KeepSpeed,
+        RetargetPolicy::BlendFromVelocity,
+    ];
+    let entities = policies.map(|_| {
+        app.world_mut()
+            .spawn((
+                Transform::default(),
+                <Transform as Eased>::ease(0., 100., EasingType::Once { duration: SECOND }),
+            ))
+            .id()
+    });
+
+    update(&mut app, 500);
+    for (entity, policy) in entities.into_iter().zip(policies) {
+        assert_close(value::<Transform>(&app, entity), 25.);
+        app.world_mut()
+            .get_mut::<EasingComponent<Transform>>(entity)
+            .unwrap()
+            .retarget(Transform::from_xyz(200., 0., 0.), policy);
+    }
+    let [restart, remaining_time, speed, blend] = entities;
+
+    update(&mut app, 250);
+    assert_close(
+        value::<Transform>(&app, restart),
+        25. + 175. * eased(0.25) / 100.,
+    );
+    assert_close(
+        value::<Transform>(&app, remaining_time),
+        25. + 175. * eased(0.5) / 100.,
+    );
+    // continues on the curve from its progress when retargeted
+    assert_close(
+        value::<Transform>(&app, speed),
+        25. + 175. * (eased(0.75) - 25.) / 75.,
+    );
+    // blends the previous movement, continuing toward 100, into the new one
+    assert_close(
+        value::<Transform>(&app, blend),
+        (eased(0.75) + 25. + 175. * eased(0.5) / 100.) / 2.,
+    );
+
+    update(&mut app, 250);
+    for entity in [remaining_time, speed, blend] {
+        assert_close(value::<Transform>(&app, entity), 200.);
+        assert!(
+            app.world()
+                .get::<EasingComponent<Transform>>(entity)
+                .is_none()
+        );
+    }
+    assert_close(
+        value::<Transform>(&app, restart),
+        25. + 175. * eased(0.5) / 100.,
+    );
+}
+
+#[test]
+fn rotation_modes() {
+    use std::f32::consts::PI;
+
+    let mut app = app();
+    let linear = EasingType::Once { duration: SECOND };
+    let quarter_turns = Transform::from_rotation(Quat::from_rotation_z(3. * PI / 2.));
+    let spin = app
+        .world_mut()
+        .spawn((
+            Transform::default(),
+            Transform::default()
+                .ease_to(Transform::default(), EaseMethod::Linear, linear)
+                .with_rotation(RotationMode::spin_z(2.)),
+        ))
+        .id();
+    let shortest = app
+        .world_mut()
+        .spawn((
+            Transform::default(),
+            Transform::default()
+                .ease_to(quarter_turns, EaseMethod::Linear, linear)
+                .with_rotation(RotationMode::ShortestPath),
+        ))
+        .id();
+    let slerp = app
+        .world_mut()
+        .spawn((
+            Transform::default(),
+            Quat::IDENTITY
+                .ease_to(quarter_turns.rotation, EaseMethod::Linear, linear)
+                .with_rotation(RotationMode::Slerp)
+                .with_lens(TransformRotationLens),
+        ))
+        .id();
+    let rotation = |app: &App, entity| app.world().get::<Transform>(entity).unwrap().rotation;
+
+    update(&mut app, 125);
+    assert_close(
+        rotation(&app, spin).angle_between(Quat::from_rotation_z(PI / 2.)),
+        0.,
+    );
+    update(&mut app, 250);
+    // two full turns, going through rotations a shortest path would skip
+    assert_close(
+        rotation(&app, spin).angle_between(Quat::from_rotation_z(3. * PI / 2.)),
+        0.,
+    );
+    update(&mut app, 125);
+    // turns a quarter clockwise instead of three quarters counterclockwise
+    assert_close(
+        rotation(&app, shortest).angle_between(Quat::from_rotation_z(-PI / 4.)),
+        0.,
+    );
+    // follows the quaternions, the long way around
+    assert_close(
+        rotation(&app, slerp).angle_between(Quat::from_rotation_z(3. * PI / 4.)),
+        0.,
+    );
+    update(&mut app, 500);
+    assert_close(rotation(&app, spin).angle_between(Quat::IDENTITY), 0.);
+    assert_close(
+        rotation(&app, slerp).angle_between(quarter_turns.rotation),
+        0.,
+    );
+}
+
+#[test]
+fn hue_interpolation() {
+    let mut app = app();
+    let hue =
+        |app: &App, entity| Hsla::from(app.world().get::<BackgroundColor>(entity).unwrap().0).hue;
+    let ease = |start: f32, end: f32| {
+        BackgroundColor(Color::hsl(start, 1., 0.5)).ease_to(
+            BackgroundColor(Color::hsl(end, 1., 0.5)),
+            EaseMethod::Linear,
+            EasingType::Once { duration: SECOND },
+        )
+    };
+    let shorter = app
+        .world_mut()
+        .spawn((BackgroundColor::default(), ease(350., 10.)))
+        .id();
+    let longer = app
+        .world_mut()
+        .spawn((
+            BackgroundColor::default(),
+            ease(350., 10.).with_hue(HueInterpolation::Longer),
+        ))
+        .id();
+    let decreasing = app
+        .world_mut()
+        .spawn((
+            BackgroundColor::default(),
+            ease(10., 20.).with_color_mix(ColorMix::new().hue(HueInterpolation::Decreasing)),
+        ))
+        .id();
+
+    update(&mut app, 500);
+    assert_close(hue(&app, shorter), 0.);
+    assert_close(hue(&app, longer), 180.);
+    assert_close(hue(&app, decreasing), 195.);
+    update(&mut app, 500);
+    assert_close(hue(&app, longer), 10.);
+    assert_close(hue(&app, decreasing), 20.);
+}
+
+#[test]
+fn color_mix_resource() {
+    let mut other_app = app();
+    let mut app = app();
+    app.insert_resource(ColorMix::new().hue(HueInterpolation::Longer));
+    let hue =
+        |app: &App, entity| Hsla::from(app.world().get::<BackgroundColor>(entity).unwrap().0).hue;
+    let ease = || {
+        BackgroundColor(Color::hsl(350., 1., 0.5)).ease_to(
+            BackgroundColor(Color::hsl(10., 1., 0.5)),
+            EaseMethod::Linear,
+            EasingType::Once { duration: SECOND },
+        )
+    };
+    let resource = app
+        .world_mut()
+        .spawn((BackgroundColor::default(), ease()))
+        .id();
+    // only the color space is changed, the hue still follows the resource
+    let space = app
+        .world_mut()
+        .spawn((
+            BackgroundColor::default(),
+            ease().with_color_space(ColorInterpolation::Hsl),
+        ))
+        .id();
+    let own = app
+        .world_mut()
+        .spawn((
+            BackgroundColor::default(),
+            ease().with_color_mix(ColorMix::new()),
+        ))
+        .id();
+    let gradient = ColorGradient::new()
+        .stop(0., Color::hsl(350., 1., 0.5), EaseMethod::Linear)
+        .stop(1., Color::hsl(10., 1., 0.5), EaseMethod::Linear);
+    let gradient_entity = app
+        .world_mut()
+        .spawn((
+            BackgroundColor::default(),
+            BackgroundColor::default().ease_gradient(
+                gradient.clone(),
+                EaseMethod::Linear,
+                EasingType::Once { duration: SECOND },
+            ),
+        ))
+        .id();
+    // outside of the easing systems, the settings are given explicitly
+    assert_close(Hsla::from(gradient.sample(0.5, &ColorMix::new())).hue, 0.);
+    // another app keeps the default settings
+    let other = other_app
+        .world_mut()
+        .spawn((BackgroundColor::default(), ease()))
+        .id();
+
+    update(&mut app, 500);
+    update(&mut other_app, 500);
+    assert_close(hue(&app, resource), 180.);
+    assert_close(hue(&app, space), 180.);
+    assert_close(hue(&app, own), 0.);
+    assert_close(hue(&app, gradient_entity), 180.);
+    assert_close(hue(&other_app, other), 0.);
+}
+
+#[test]
+fn color_interpolation_space() {
+    let mut app = app();
+    let ease = || {
+        BackgroundColor(Color::Srgba(Srgba::RED)).ease_to(
+            BackgroundColor(Color::Srgba(Srgba::BLUE)),
+            EaseMethod::Linear,
+            EasingType::Once { duration: SECOND },
+        )
+    };
+    let srgb = app
+        .world_mut()
+        .spawn((BackgroundColor::default(), ease()))
+        .id();
+    let oklab = app
+        .world_mut()
+        .spawn((
+            BackgroundColor::default(),
+            ease().with_color_space(ColorInterpolation::Oklab),
+        ))
+        .id();
+    let color = |app: &App, entity| app.world().get::<BackgroundColor>(entity).unwrap().0;
+
+    update(&mut app, 500);
+    assert_eq!(
+        color(&app, srgb),
+        Color::Srgba(Srgba::new(0.5, 0., 0.5, 1.))
+    );
+    // interpolated in Oklab, then converted back to the color space of the start color
+    let Color::Srgba(mixed) = color(&app, oklab) else {
+        panic!("color should stay in sRGB");
+    };
+    let expected = Srgba::from(Oklaba::from(Srgba::RED).mix(&Oklaba::from(Srgba::BLUE), 0.5));
+    assert_close(mixed.red, expected.red);
+    assert_close(mixed.green, expected.green);
+    assert_close(mixed.blue, expected.blue);
+    assert!((mixed.red - 0.5).abs() > 0.05);
+}
+
+#[test]
+fn premultiplied_alpha() {
+    let mut app = app();
+    let ease = || {
+        Sprite::from_color(Srgba::new(1., 0., 0., 0.), Vec2::ONE).ease_to(
+            Sprite::from_color(Srgba::BLUE, Vec2::ONE),
+            EaseMethod::Linear,
+            EasingType::Once { duration: SECOND },
+        )
+    };
+    let straight = app.world_mut().spawn((Sprite::default(), ease())).id();
+    let premultiplied = app
+        .world_mut()
+        .spawn((Sprite::default(), ease().with_premultiplied_alpha()))
+        .id();
+    let color = |app: &App, entity| app.world().get::<Sprite>(entity).unwrap().color;
+
+    update(&mut app, 500);
+    assert_eq!(
+        color(&app, straight),
+        Color::Srgba(Srgba::new(0.5, 0., 0.5, 0.5))
+    );
+    // the transparent red doesn't tint the blue
+    assert_eq!(
+        color(&app, premultiplied),
+        Color::Srgba(Srgba::new(0., 0., 1., 0.5))
+    );
+}
+
+#[test]
+fn color_gradient() {
+    let mut app = app();
+    let (green, yellow, red) = (
+        Srgba::new(0., 1., 0., 1.),
+        Srgba::new(1., 1., 0., 1.),
+        Srgba::new(1., 0., 0., 1.),
+    );
+    let gradient = ColorGradient::new()
+        .stop(1., red, EaseFunction::QuadraticIn)
+        .stop(0., green, EaseMethod::Linear)
+        .stop(0.5, yellow, EaseMethod::Linear);
+    let background = app
+        .world_mut()
+        .spawn((
+            BackgroundColor::default(),
+            BackgroundColor(green.into()).ease_gradient(
+                gradient.clone(),
+                EaseMethod::Linear,
+                EasingType::Once { duration: SECOND },
+            ),
+        ))
+        .id();
+    let border = app
+        .world_mut()
+        .spawn((
+            BorderColor::default(),
+            BorderColor::all(green).ease_gradient(
+                gradient.clone(),
+                EaseMethod::Linear,
+                EasingType::Once { duration: SECOND },
+            ),
+        ))
+        .id();
+    let sprite = Sprite::from_color(green, Vec2::new(10., 20.));
+    let sprite = app
+        .world_mut()
+        .spawn((
+            Sprite::default(),
+            sprite.ease_gradient(
+                gradient.space(ColorInterpolation::Oklab),
+                EaseMethod::Linear,
+                EasingType::Once { duration: SECOND },
+            ),
+        ))
+        .id();
+    let srgba = |color: Color| Srgba::from(color);
+
+    update(&mut app, 250);
+    let color = srgba(app.world().get::<BackgroundColor>(background).unwrap().0);
+    assert_close(color.red, 0.5);
+    assert_close(color.green, 1.);
+    update(&mut app, 250);
+    assert_eq!(
+        app.world().get::<BackgroundColor>(background).unwrap().0,
+        Color::Srgba(yellow)
+    );
+    assert_eq!(
+        *app.world().get::<BorderColor>(border).unwrap(),
+        BorderColor::all(yellow)
+    );
+    update(&mut app, 250);
+    // the last segment is eased with its own ease function
+    let color = srgba(app.world().get::<BackgroundColor>(background).unwrap().0);
+    assert_close(color.green, 1. - 0.25);
+    let expected = srgba(Color::Srgba(yellow).lerp_color(
+        &Color::Srgba(red),
+        0.25,
+        &ColorMix::new().space(ColorInterpolation::Oklab),
+    ));
+    let sprite = app.world().get::<Sprite>(sprite).unwrap();
+    assert_close(srgba(sprite.color).green, expected.green);
+    // the other fields are kept
+    assert_eq!(sprite.custom_size, Some(Vec2::new(10., 20.)));
+    update(&mut app, 250);
+    assert_eq!(
+        app.world().get::<BackgroundColor>(background).unwrap().0,
+        Color::Srgba(red)
+    );
+}
+
+#[test]
+fn ui_components() {
+    let mut app = app();
+    let linear = EasingType::Once { duration: SECOND };
+    let entity = app
+        .world_mut()
+        .spawn((
+            Outline::default(),
+            BoxShadow::default(),
+            BorderRadius::default(),
+            UiTransform::default(),
+            ImageNode::default(),
+            Outline::new(Val::Px(0.), Val::Px(0.), Color::BLACK).ease_to(
+                Outline::new(Val::Px(10.), Val::Px(4.), Color::WHITE),
+                EaseMethod::Linear,
+                linear,
+            ),
+            BoxShadow::new(
+                Color::BLACK,
+                Val::Px(0.),
+                Val::Px(0.),
+                Val::Px(0.),
+                Val::Px(0.),
+            )
+            .ease_to(
+                BoxShadow::new(
+                    Color::BLACK,
+                    Val::Px(10.),
+                    Val::Px(20.),
+                    Val::Px(0.),
+                    Val::Px(8.),
+                ),
+                EaseMethod::Linear,
+                linear,
+            ),
+            BorderRadius::ZERO.ease_to(BorderRadius::all(Val::Px(16.)), EaseMethod::Linear, linear),
+            UiTransform::IDENTITY.ease_to(
+                UiTransform {
+                    translation: Val2::px(100., 0.),
+                    scale: Vec2::splat(2.),
+                    rotation: Rot2::degrees(90.),
+                },
+                EaseMethod::Linear,
+                linear,
+            ),
+            ImageNode::default().with_color(Color::NONE).ease_to(
+                ImageNode::default(),
+                EaseMethod::Linear,
+                linear,
+            ),
+        ))
+        .id();
+
+    update(&mut app, 500);
+    let world = app.world();
+    let outline = world.get::<Outline>(entity).unwrap();
+    assert_eq!(outline.width, Val::Px(5.));
+    assert_eq!(outline.offset, Val::Px(2.));
+    let shadow = &world.get::<BoxShadow>(entity).unwrap().0[0];
+    assert_eq!(shadow.x_offset, Val::Px(5.));
+    assert_eq!(shadow.y_offset, Val::Px(10.));
+    assert_eq!(shadow.blur_radius, Val::Px(4.));
+    assert_eq!(
+        *world.get::<BorderRadius>(entity).unwrap(),
+        BorderRadius::all(Val::Px(8.))
+    );
+    let transform = world.get::<UiTransform>(entity).unwrap();
+    assert_eq!(transform.translation, Val2::px(50., 0.));
+    assert_close(transform.scale.x, 1.5);
+    assert_close(transform.rotation.as_degrees(), 45.);
+    assert_close(world.get::<ImageNode>(entity).unwrap().color.alpha(), 0.5);
+}
+
+#[test]
+fn box_shadow_fades_in_and_out() {
+    let mut app = app();
+    let linear = EasingType::Once { duration: SECOND };
+    let shadow = |blur: f32| ShadowStyle {
+        color: Color::BLACK,
+        x_offset: Val::Px(10.),
+        y_offset: Val::Px(10.),
+        spread_radius: Val::Px(0.),
+        blur_radius: Val::Px(blur),
+    };
+    let entity = app
+        .world_mut()
+        .spawn((
+            BoxShadow::default(),
+            BoxShadow(vec![shadow(8.)]).ease_to(
+                BoxShadow(vec![shadow(8.), shadow(4.)]),
+                EaseMethod::Linear,
+                linear,
+            ),
+        ))
+        .id();
+    let faded = app
+        .world_mut()
+        .spawn((
+            BoxShadow::default(),
+            BoxShadow(vec![shadow(8.)]).ease_to(BoxShadow(vec![]), EaseMethod::Linear, linear),
+        ))
+        .id();
+
+    update(&mut app, 500);
+    let shadows = &app.world().get::<BoxShadow>(entity).unwrap().0;
+    assert_eq!(shadows.len(), 2);
+    assert_eq!(shadows[1].x_offset, Val::Px(10.));
+    assert_eq!(shadows[1].blur_radius, Val::Px(2.));
+    assert_close(shadows[1].color.alpha(), 0.5);
+    let shadows = &app.world().get::<BoxShadow>(faded).unwrap().0;
+    assert_eq!(shadows[0].blur_radius, Val::Px(4.));
+    assert_close(shadows[0].color.alpha(), 0.5);
+}